@@ -0,0 +1,76 @@
+//! Times word segmentation and conversion over a batch of identifiers.  Run with
+//! `cargo bench --bench split`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use convert_case::{split, Boundary, Case, Casing};
+
+const IDENTIFIERS: &[&str] = &[
+    "XMLHttpRequest",
+    "getTotalLength",
+    "my_snake_case_variable_name",
+    "SCREAMING_SNAKE_CASE_2024",
+    "kebab-case-with-many-words-in-it",
+    "Title Case With Spaces And Numbers 3d",
+    "Transformations In 3D Space",
+    "userID2Name",
+    "música moderna",
+    "ПЕРСПЕКТИВА24",
+];
+
+fn bench<F: FnMut()>(name: &str, iterations: u32, mut f: F) {
+    // warm up before timing
+    for _ in 0..iterations / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    let per_iter: Duration = start.elapsed() / iterations;
+    println!("{:<32} {:>10.2?} / iter", name, per_iter);
+}
+
+fn main() {
+    let defaults = Boundary::defaults();
+    let all = Boundary::all();
+    let long = IDENTIFIERS.join(" ").repeat(20);
+    let long_identifier = "getXMLHttpRequest2ForUserID".repeat(100);
+    // a custom boundary that never matches turns off the bitset check of built-in boundaries
+    let mut general = Boundary::defaults().to_vec();
+    general.push(Boundary::from_delim("::"));
+    let mut wide = Boundary::all().to_vec();
+    wide.extend(Boundary::from_delims(&[
+        "::", ".", "/", "\\", "|", "+", "=", "~", "#", "@", "<", ">", ",", ";", "!", "?", "%", "&",
+        "*", "^",
+    ]));
+
+    bench("split defaults", 20_000, || {
+        for s in IDENTIFIERS {
+            black_box(split(black_box(s), &defaults));
+        }
+    });
+    bench("split all boundaries", 20_000, || {
+        for s in IDENTIFIERS {
+            black_box(split(black_box(s), &all));
+        }
+    });
+    bench("split long string", 500, || {
+        black_box(split(black_box(&long), &defaults));
+    });
+    bench("split long string, wide", 500, || {
+        black_box(split(black_box(&long), &wide));
+    });
+    bench("split long identifier", 500, || {
+        black_box(split(black_box(&long_identifier), &defaults));
+    });
+    bench("split long identifier, general", 500, || {
+        black_box(split(black_box(&long_identifier), &general));
+    });
+    bench("to_case snake", 20_000, || {
+        for s in IDENTIFIERS {
+            black_box(black_box(s).to_case(Case::Snake));
+        }
+    });
+}
@@ -1,8 +1,19 @@
 #[cfg(test)]
 use strum::EnumIter;
 
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
 use crate::pattern::Pattern;
+use crate::segmentation::Words;
 use crate::Boundary;
+use crate::Casing;
+use crate::Converter;
 
 /// Defines the type of casing a string can be.
 ///
@@ -77,8 +88,8 @@ pub enum Case {
     /// Camel case strings are lowercase, but for every word _except the first_ the
     /// first letter is capitalized.
     /// * Boundaries: [LowerUpper](Boundary::LowerUpper), [DigitUpper](Boundary::DigitUpper),
-    /// [UpperDigit](Boundary::UpperDigit), [DigitLower](Boundary::DigitLower),
-    /// [LowerDigit](Boundary::LowerDigit), [Acronym](Boundary::Acronym)
+    ///   [UpperDigit](Boundary::UpperDigit), [DigitLower](Boundary::DigitLower),
+    ///   [LowerDigit](Boundary::LowerDigit), [Acronym](Boundary::Acronym)
     /// * Pattern: [Camel](`Pattern::Camel`)
     /// * Delimeter: No delimeter
     ///
@@ -91,8 +102,8 @@ pub enum Case {
     /// Pascal case strings are lowercase, but for every word the
     /// first letter is capitalized.
     /// * Boundaries: [LowerUpper](Boundary::LowerUpper), [DigitUpper](Boundary::DigitUpper),
-    /// [UpperDigit](Boundary::UpperDigit), [DigitLower](Boundary::DigitLower),
-    /// [LowerDigit](Boundary::LowerDigit), [Acronym](Boundary::Acronym)
+    ///   [UpperDigit](Boundary::UpperDigit), [DigitLower](Boundary::DigitLower),
+    ///   [LowerDigit](Boundary::LowerDigit), [Acronym](Boundary::Acronym)
     /// * Pattern: [Capital](`Pattern::Capital`)
     /// * Delimeter: No delimeter
     ///
@@ -167,6 +178,33 @@ pub enum Case {
     /// ```
     Train,
 
+    /// Path case strings are delimited by slashes `/` and are all lowercase.  Only slashes are
+    /// used as boundaries, so converting from path case will not split digits from letters
+    /// like the [default boundaries](Boundary::defaults) do.
+    /// * Boundaries: [Custom](Boundary::from_delim) boundary on `/`
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Delimeter: Slash `/`
+    ///
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// assert_eq!("my/variable/name", "My variable NAME".to_case(Case::Path));
+    /// assert_eq!("src/v2/lib", "src/v2/lib".from_case(Case::Path).to_case(Case::Path));
+    /// ```
+    Path,
+
+    /// Dot case strings are delimited by periods `.` and are all lowercase.  Like path case,
+    /// only periods are used as boundaries.
+    /// * Boundaries: [Custom](Boundary::from_delim) boundary on `.`
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Delimeter: Period `.`
+    ///
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// assert_eq!("my.variable.name", "My variable NAME".to_case(Case::Dot));
+    /// assert_eq!("server_v2_port", "server.v2.port".from_case(Case::Dot).to_case(Case::Snake));
+    /// ```
+    Dot,
+
     /// Flat case strings are all lowercase, with no delimiter. Note that word boundaries are lost.
     /// * Boundaries: No boundaries
     /// * Pattern: [Lowercase](Pattern::Lowercase)
@@ -229,7 +267,7 @@ pub enum Case {
     /// ```
     /// use convert_case::{Case, Casing};
     /// # #[cfg(any(doc, feature = "random"))]
-    /// let new = "My variable NAME".to_case(Case::Random);
+    /// let new = "My variable NAME".to_case(Case::PseudoRandom);
     /// ```
     /// String `new` could be "mY vArIAblE NamE" for example.
     #[cfg(any(doc, feature = "random"))]
@@ -246,6 +284,8 @@ impl Case {
     /// | Upper, Lower, Title, Toggle, Alternating, Random, PseudoRandom | Space |
     /// | Snake, UpperSnake, ScreamingSnake | Underscore `_` |
     /// | Kebab, Cobol, UpperKebab, Train | Hyphen `-` |
+    /// | Path | Slash `/` |
+    /// | Dot | Period `.` |
     /// | UpperFlat, Flat, Camel, UpperCamel, Pascal | Empty string, no delimeter |
     pub const fn delim(&self) -> &'static str {
         use Case::*;
@@ -253,6 +293,8 @@ impl Case {
             Upper | Lower | Title | Toggle | Alternating => " ",
             Snake | UpperSnake | ScreamingSnake => "_",
             Kebab | Cobol | UpperKebab | Train => "-",
+            Path => "/",
+            Dot => ".",
 
             #[cfg(feature = "random")]
             Random | PseudoRandom => " ",
@@ -267,7 +309,7 @@ impl Case {
     /// | Cases | Pattern |
     /// | --- | --- |
     /// | Upper, UpperSnake, ScreamingSnake, UpperFlat, Cobol, UpperKebab | Uppercase |
-    /// | Lower, Snake, Kebab, Flat | Lowercase |
+    /// | Lower, Snake, Kebab, Path, Dot, Flat | Lowercase |
     /// | Title, Pascal, UpperCamel, Train | Capital |
     /// | Camel | Camel |
     /// | Alternating | Alternating |
@@ -279,7 +321,7 @@ impl Case {
             Upper | UpperSnake | ScreamingSnake | UpperFlat | Cobol | UpperKebab => {
                 Pattern::Uppercase
             }
-            Lower | Snake | Kebab | Flat => Pattern::Lowercase,
+            Lower | Snake | Kebab | Path | Dot | Flat => Pattern::Lowercase,
             Title | Pascal | UpperCamel | Train => Pattern::Capital,
             Camel => Pattern::Camel,
             Toggle => Pattern::Toggle,
@@ -301,26 +343,210 @@ impl Case {
     /// | Upper, Lower, Title, Toggle, Alternating, Random, PseudoRandom | Space |
     /// | Snake, UpperSnake, ScreamingSnake | Underscore `_` |
     /// | Kebab, Cobol, UpperKebab, Train | Hyphen `-` |
+    /// | Path | Slash `/` |
+    /// | Dot | Period `.` |
     /// | Camel, UpperCamel, Pascal | LowerUpper, LowerDigit, UpperDigit, DigitLower, DigitUpper, Acronym |
     /// | UpperFlat, Flat | No boundaries |
     pub fn boundaries(&self) -> Vec<Boundary> {
+        self.boundary_slice().to_vec()
+    }
+
+    /// The boundaries of the case, borrowed so that splitting with them doesn't allocate.
+    fn boundary_slice(&self) -> &'static [Boundary] {
         use Boundary::*;
         use Case::*;
+        const SLASH: &[Boundary] = &[Boundary::from_ascii_delim("/")];
+        const PERIOD: &[Boundary] = &[Boundary::from_ascii_delim(".")];
         match self {
-            Upper | Lower | Title | Toggle | Alternating => vec![Space],
-            Snake | UpperSnake | ScreamingSnake => vec![Underscore],
-            Kebab | Cobol | UpperKebab | Train => vec![Hyphen],
+            Upper | Lower | Title | Toggle | Alternating => &[Space],
+            Snake | UpperSnake | ScreamingSnake => &[Underscore],
+            Kebab | Cobol | UpperKebab | Train => &[Hyphen],
+            Path => SLASH,
+            Dot => PERIOD,
 
             #[cfg(feature = "random")]
-            Random | PseudoRandom => vec![Space],
+            Random | PseudoRandom => &[Space],
 
-            UpperFlat | Flat => vec![],
-            Camel | UpperCamel | Pascal => vec![
+            UpperFlat | Flat => &[],
+            Camel | UpperCamel | Pascal => &[
                 LowerUpper, Acronym, LowerDigit, UpperDigit, DigitLower, DigitUpper,
             ],
         }
     }
 
+    /// Splits a string into words using the boundaries associated with the case, yielding
+    /// each word as it is found.  This is the same as calling [`split_iter`](crate::split_iter)
+    /// with [`Case::boundaries`].
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(3, Case::Camel.split_iter(&"getTotalLength").count());
+    /// assert_eq!(
+    ///     vec!["my", "var", "name"],
+    ///     Case::Snake.split_iter(&"my_var__name").collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn split_iter<'s, T>(&self, s: &'s T) -> impl Iterator<Item = &'s str>
+    where
+        T: AsRef<str> + ?Sized,
+    {
+        Words::new(s.as_ref(), Cow::Borrowed(self.boundary_slice()))
+    }
+
+    /// Returns a [`Converter`] into this case that writes the given acronyms in uppercase
+    /// wherever the case would capitalize them.  Acronyms match whole words, ignoring case.
+    /// To keep an acronym's own spelling, like "iOS", use [`Converter::with_acronyms`].
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// let pascal = Case::Pascal.with_acronyms(&["io", "xml", "http"]);
+    /// assert_eq!("IOStream", pascal.convert("io_stream"));
+    /// assert_eq!("XMLHTTPRequest", pascal.convert("XmlHttpRequest"));
+    /// ```
+    pub fn with_acronyms(&self, acronyms: &[&str]) -> Converter {
+        let acronyms: Vec<String> = acronyms.iter().map(|a| a.to_uppercase()).collect();
+        let acronyms: Vec<&str> = acronyms.iter().map(String::as_str).collect();
+        Converter::new().to_case(*self).with_acronyms(&acronyms)
+    }
+
+    /// Compares the pattern, delimiter, and boundaries of two cases.  This is useful for
+    /// describing how far apart two cases are, such as telling someone that only the delimiter
+    /// of their identifier differs from the convention.
+    ///
+    /// Boundaries that split on a case's own delimiter are implied by the delimiter, so they
+    /// aren't compared.  That is, snake case and kebab case only differ by delimiter.
+    /// ```
+    /// use convert_case::{Case, CaseDiff};
+    ///
+    /// assert_eq!(
+    ///     CaseDiff { pattern: false, delim: true, boundaries: false },
+    ///     Case::Snake.difference(&Case::Kebab)
+    /// );
+    /// assert_eq!(
+    ///     CaseDiff { pattern: true, delim: false, boundaries: false },
+    ///     Case::Camel.difference(&Case::Pascal)
+    /// );
+    /// assert!(Case::Pascal.difference(&Case::UpperCamel).is_identical());
+    /// ```
+    pub fn difference(&self, other: &Case) -> CaseDiff {
+        CaseDiff {
+            pattern: self.pattern() != other.pattern(),
+            delim: self.delim() != other.delim(),
+            boundaries: !same_boundaries(&self.implicit_boundaries(), &other.implicit_boundaries()),
+        }
+    }
+
+    /// Returns the name of the case, with words separated by spaces.  This is what
+    /// `Display` writes, and it parses back into the same case.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!("Upper Snake", Case::UpperSnake.name());
+    /// assert_eq!(Ok(Case::UpperSnake), Case::UpperSnake.name().parse());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        use Case::*;
+        match self {
+            Upper => "Upper",
+            Lower => "Lower",
+            Title => "Title",
+            Toggle => "Toggle",
+            Camel => "Camel",
+            Pascal => "Pascal",
+            UpperCamel => "Upper Camel",
+            Snake => "Snake",
+            UpperSnake => "Upper Snake",
+            ScreamingSnake => "Screaming Snake",
+            Kebab => "Kebab",
+            Cobol => "Cobol",
+            UpperKebab => "Upper Kebab",
+            Train => "Train",
+            Path => "Path",
+            Dot => "Dot",
+            Flat => "Flat",
+            UpperFlat => "Upper Flat",
+            Alternating => "Alternating",
+
+            #[cfg(feature = "random")]
+            Random => "Random",
+            #[cfg(feature = "random")]
+            PseudoRandom => "Pseudo Random",
+        }
+    }
+
+    /// Returns the case that this case is an alternative name for, or the case itself if it
+    /// isn't an alias.  `UpperCamel` is an alias for `Pascal`, `ScreamingSnake` for
+    /// `UpperSnake`, and `UpperKebab` for `Cobol`.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(Case::Pascal, Case::UpperCamel.canonical());
+    /// assert_eq!(Case::Snake, Case::Snake.canonical());
+    /// ```
+    pub const fn canonical(&self) -> Case {
+        match self {
+            Case::UpperCamel => Case::Pascal,
+            Case::ScreamingSnake => Case::UpperSnake,
+            Case::UpperKebab => Case::Cobol,
+            case => *case,
+        }
+    }
+
+    /// Returns the case that this case is an alias for, or `None` if it is not an alias.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(Some(Case::UpperSnake), Case::ScreamingSnake.is_alias());
+    /// assert_eq!(None, Case::UpperSnake.is_alias());
+    /// ```
+    pub const fn is_alias(&self) -> Option<Case> {
+        match self {
+            Case::UpperCamel | Case::ScreamingSnake | Case::UpperKebab => Some(self.canonical()),
+            _ => None,
+        }
+    }
+
+    /// Returns the case with the same delimiter that uses lowercase letters where this case
+    /// uses uppercase letters, or the other way around.  Snake and upper snake, kebab and
+    /// cobol, flat and upper flat, lower and upper, and camel and pascal are each other's
+    /// opposites.  Aliases map to the opposite of the case they stand for.  Cases without a
+    /// natural opposite, like title or alternating case, return themselves.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(Case::UpperSnake, Case::Snake.invert_casing());
+    /// assert_eq!(Case::Camel, Case::Pascal.invert_casing());
+    /// assert_eq!(Case::Title, Case::Title.invert_casing());
+    ///
+    /// let name = "max_size";
+    /// assert_eq!("MAX_SIZE", name.to_case(Case::Snake.invert_casing()));
+    /// ```
+    pub const fn invert_casing(&self) -> Case {
+        use Case::*;
+        match self.canonical() {
+            Snake => UpperSnake,
+            UpperSnake => Snake,
+            Kebab => Cobol,
+            Cobol => Kebab,
+            Flat => UpperFlat,
+            UpperFlat => Flat,
+            Lower => Upper,
+            Upper => Lower,
+            Camel => Pascal,
+            Pascal => Camel,
+            case => case,
+        }
+    }
+
+    /// Boundaries of the case, excluding those that split on the case's delimiter.
+    fn implicit_boundaries(&self) -> Vec<Boundary> {
+        let delim = self.delim();
+        self.boundaries()
+            .into_iter()
+            .filter(|b| b.delim() != Some(delim))
+            .collect()
+    }
+
     // Created to avoid using the EnumIter trait from strum in
     // final library.  A test confirms that all cases are listed here.
     /// Returns a vector with all case enum variants in no particular order.
@@ -341,6 +567,49 @@ impl Case {
             Cobol,
             UpperKebab,
             Train,
+            Path,
+            Dot,
+            Flat,
+            UpperFlat,
+            Alternating,
+            #[cfg(feature = "random")]
+            Random,
+            #[cfg(feature = "random")]
+            PseudoRandom,
+        ]
+    }
+
+    /// Returns every case variant, including the aliases `UpperCamel`, `ScreamingSnake`
+    /// and `UpperKebab`, as a static slice.  Use [`is_alias`](Case::is_alias) to tell the
+    /// aliases apart, for example when listing cases in documentation or a menu.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// let aliases: Vec<_> = Case::all_cases_with_aliases()
+    ///     .iter()
+    ///     .filter(|case| case.is_alias().is_some())
+    ///     .collect();
+    /// assert_eq!(vec![&Case::UpperCamel, &Case::ScreamingSnake, &Case::UpperKebab], aliases);
+    /// ```
+    pub fn all_cases_with_aliases() -> &'static [Case] {
+        use Case::*;
+        &[
+            Upper,
+            Lower,
+            Title,
+            Toggle,
+            Camel,
+            Pascal,
+            UpperCamel,
+            Snake,
+            UpperSnake,
+            ScreamingSnake,
+            Kebab,
+            Cobol,
+            UpperKebab,
+            Train,
+            Path,
+            Dot,
             Flat,
             UpperFlat,
             Alternating,
@@ -378,11 +647,377 @@ impl Case {
             Cobol,
             UpperKebab,
             Train,
+            Path,
+            Dot,
             Flat,
             UpperFlat,
             Alternating,
         ]
     }
+
+    /// Checks that `s` has the structure of this case, which is stricter than
+    /// [`Casing::is_case`].  Every word between delimiters must be nonempty and contain only
+    /// letters and digits, and the letters must be cased as the pattern would case them.  For
+    /// example, snake case strings match `[a-z0-9]+(_[a-z0-9]+)*` for ASCII input.  Cases
+    /// without a delimiter, like camel and pascal, only check the case of the first letter.
+    ///
+    /// Alternating case and the random cases have no fixed structure, so they fall back to
+    /// `is_case`.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert!(Case::Snake.matches_pattern("my_var_2"));
+    /// assert!(!Case::Snake.matches_pattern("my__var"));
+    /// assert!(Case::Pascal.matches_pattern("XMLHttpRequest"));
+    /// assert!(!Case::Camel.matches_pattern("my-var"));
+    /// ```
+    pub fn matches_pattern(&self, s: &str) -> bool {
+        let delim = self.delim();
+        let words: Vec<&str> = if delim.is_empty() {
+            vec![s]
+        } else {
+            s.split(delim).collect()
+        };
+        if words
+            .iter()
+            .any(|word| word.is_empty() || !word.chars().all(char::is_alphanumeric))
+        {
+            return false;
+        }
+
+        let no_upper = |word: &&str| !word.chars().any(char::is_uppercase);
+        let no_lower = |word: &&str| !word.chars().any(char::is_lowercase);
+        let first = |word: &str, f: fn(char) -> bool| word.chars().next().is_some_and(f);
+        let capital = |word: &&str| {
+            let mut chars = word.chars();
+            !chars.next().is_some_and(char::is_lowercase) && !chars.any(char::is_uppercase)
+        };
+        match (self.pattern(), delim.is_empty()) {
+            (Pattern::Lowercase, _) => words.iter().all(no_upper),
+            (Pattern::Uppercase, _) => words.iter().all(no_lower),
+            (Pattern::Camel, true) => first(s, char::is_lowercase),
+            (Pattern::Capital, true) => first(s, char::is_uppercase),
+            (Pattern::Capital, false) => words.iter().all(capital),
+            (Pattern::Toggle, _) => words.iter().all(|word| {
+                let mut chars = word.chars();
+                !chars.next().is_some_and(char::is_uppercase) && !chars.any(char::is_lowercase)
+            }),
+            _ => s.is_case(*self),
+        }
+    }
+
+    /// Returns every deterministic case that `s` is already in, that is, each case where
+    /// converting `s` from and to that case leaves it unchanged.  Cases are listed in the
+    /// same order as [`Case::deterministic_cases`].
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(vec![Case::Camel], Case::detect_all("myVarName"));
+    /// assert!(Case::detect_all("MY_VAR").contains(&Case::ScreamingSnake));
+    /// assert!(Case::detect_all("my Var_name").is_empty());
+    /// ```
+    pub fn detect_all<T: AsRef<str> + ?Sized>(s: &T) -> Vec<Case> {
+        let s = s.as_ref();
+        Case::deterministic_cases()
+            .into_iter()
+            .filter(|&case| s.from_case(case).to_case(case) == s)
+            .collect()
+    }
+
+    /// Returns the most likely case of `s` among those from [`Case::detect_all`], or `None`
+    /// if `s` is in none of them.  When `s` is in more than one case, the case that splits
+    /// `s` into the most words is chosen, since it explains the most of the string's structure.
+    /// Any remaining tie goes to the case listed first in [`Case::deterministic_cases`],
+    /// so a single lowercase word is `Lower` and `UpperSnake` is chosen over `ScreamingSnake`.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert_eq!(Some(Case::Snake), Case::detect("my_var_name"));
+    /// assert_eq!(Some(Case::Pascal), Case::detect("MyVarName"));
+    /// assert_eq!(Some(Case::Lower), Case::detect("asef"));
+    /// assert_eq!(None, Case::detect("my Var_name"));
+    /// ```
+    pub fn detect<T: AsRef<str> + ?Sized>(s: &T) -> Option<Case> {
+        let s = s.as_ref();
+        // reversed so that the first case in the list wins ties
+        Case::detect_all(s)
+            .into_iter()
+            .rev()
+            .max_by_key(|case| case.split_iter(s).count())
+    }
+
+    /// Returns the case whose name is closest to `name`, for suggesting a correction when
+    /// parsing a case fails.  Names are compared in flat case, like [`FromStr`], by edit
+    /// distance.  Returns `None` if no name is within two edits.
+    /// ```
+    /// use convert_case::Case;
+    ///
+    /// assert!("cammel".parse::<Case>().is_err());
+    /// assert_eq!(Some(Case::Camel), Case::suggest("cammel"));
+    /// assert_eq!(Some(Case::UpperSnake), Case::suggest("upper-snak"));
+    /// assert_eq!(None, Case::suggest("spongebob"));
+    /// ```
+    pub fn suggest(name: &str) -> Option<Case> {
+        let name = name.to_case(Case::Flat);
+        Case::all_cases()
+            .into_iter()
+            .map(|case| {
+                let case_name = format!("{:?}", case).to_case(Case::Flat);
+                (case, edit_distance(&name, &case_name))
+            })
+            .filter(|&(_, distance)| distance <= 2)
+            .min_by_key(|&(_, distance)| distance)
+            .map(|(case, _)| case)
+    }
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+fn same_boundaries(a: &[Boundary], b: &[Boundary]) -> bool {
+    a.iter().all(|x| b.contains(x)) && b.iter().all(|x| a.contains(x))
+}
+
+/// Describes which properties differ between two cases.  Returned by [`Case::difference`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CaseDiff {
+    /// The cases mutate words with different patterns.
+    pub pattern: bool,
+
+    /// The cases join words with different delimiters.
+    pub delim: bool,
+
+    /// The cases split on different boundaries, not counting the boundaries implied by each
+    /// case's delimiter.
+    pub boundaries: bool,
+}
+
+impl CaseDiff {
+    /// Returns true if none of the properties differ.
+    pub fn is_identical(&self) -> bool {
+        !(self.pattern || self.delim || self.boundaries)
+    }
+}
+
+/// A case built at runtime, holding its own boundaries, pattern, and delimiter.  Unlike
+/// [`Case`], an `OwnedCase` can describe any combination of the three, so it can be built
+/// from a [`Converter`] with [`Converter::into_case`] and passed around or stored.
+/// ```
+/// use convert_case::{Boundary, Case, Converter, OwnedCase, Pattern};
+///
+/// let dot_case = Converter::new()
+///     .set_boundaries(&[Boundary::from_delim(".")])
+///     .set_pattern(Pattern::Lowercase)
+///     .set_delim(".")
+///     .into_case();
+///
+/// let conv = Converter::new().from_case(Case::Camel).to_owned_case(&dot_case);
+/// assert_eq!("my.var.name", conv.convert("myVarName"));
+/// assert_eq!("my_var_name", dot_case.converter().to_case(Case::Snake).convert("my.var.name"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedCase {
+    /// How a string in this case is segmented into words.
+    pub boundaries: Vec<Boundary>,
+
+    /// How words are mutated in this case, if at all.
+    pub pattern: Option<Pattern>,
+
+    /// The string used to join words in this case.
+    pub delim: String,
+}
+
+impl OwnedCase {
+    /// Returns a `Converter` that splits on the boundaries of this case and converts into
+    /// this case.
+    /// ```
+    /// use convert_case::{Case, OwnedCase};
+    ///
+    /// let snake = OwnedCase::from(Case::Snake);
+    /// assert_eq!("my_var", snake.converter().convert("my_Var"));
+    /// ```
+    pub fn converter(&self) -> Converter {
+        Converter::new()
+            .set_boundaries(&self.boundaries)
+            .to_owned_case(self)
+    }
+
+    /// Determines if `s` is in this case, like [`Casing::is_case`].  The string is split on the
+    /// boundaries of this case along with `Hyphen`, `Underscore` and `Space`, converted into
+    /// this case, and compared with the original.
+    /// ```
+    /// use convert_case::{Boundary, Converter, Pattern};
+    ///
+    /// let dot_case = Converter::new()
+    ///     .set_boundaries(&[Boundary::from_delim(".")])
+    ///     .set_pattern(Pattern::Lowercase)
+    ///     .set_delim(".")
+    ///     .into_case();
+    /// assert!(dot_case.is_case("my.new.case"));
+    /// assert!(!dot_case.is_case("myNewCase"));
+    /// ```
+    pub fn is_case<T: AsRef<str>>(&self, s: T) -> bool {
+        let s = s.as_ref();
+        let mut boundaries = self.boundaries.clone();
+        boundaries.extend(Boundary::delims());
+        Converter::new()
+            .set_boundaries(&boundaries)
+            .to_owned_case(self)
+            .convert(s)
+            == s
+    }
+}
+
+impl From<Case> for OwnedCase {
+    fn from(case: Case) -> Self {
+        OwnedCase {
+            boundaries: case.boundaries(),
+            pattern: Some(case.pattern()),
+            delim: case.delim().to_string(),
+        }
+    }
+}
+
+/// Writes the name of the case, with words separated by spaces.  Parsing the written name
+/// with [`FromStr`] returns the same case.
+/// ```
+/// use convert_case::Case;
+///
+/// assert_eq!("Upper Snake", Case::UpperSnake.to_string());
+/// assert_eq!("Kebab", Case::Kebab.to_string());
+/// assert_eq!(Ok(Case::UpperSnake), Case::UpperSnake.to_string().parse());
+/// ```
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses a case from its name.  The name is compared against the names of each variant
+/// after converting both to flat case, so `"snake"`, `"Snake"`, and `"SNAKE"` all parse as
+/// `Case::Snake`, and `"UpperSnake"`, `"upper_snake"`, and `"upper snake"` all parse as
+/// `Case::UpperSnake`.  Aliases like `"ScreamingSnake"` parse as their own variant.
+///
+/// Without the "random" feature, `"random"` and `"pseudo random"` fail to parse with an error
+/// that names the feature, so code that reads case names at runtime can report why.
+/// ```
+/// use convert_case::Case;
+///
+/// assert_eq!(Ok(Case::Kebab), "kebab".parse());
+/// assert_eq!(Ok(Case::UpperCamel), "upper-camel".parse());
+/// assert!("cammel".parse::<Case>().is_err());
+/// ```
+impl FromStr for Case {
+    type Err = ParseCaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_case(Case::Flat);
+        Case::all_cases()
+            .into_iter()
+            .find(|case| format!("{:?}", case).to_case(Case::Flat) == name)
+            .ok_or_else(|| ParseCaseError {
+                input: s.to_string(),
+                required_feature: (!cfg!(feature = "random")
+                    && ["random", "pseudorandom"].contains(&name.as_str()))
+                .then_some("random"),
+            })
+    }
+}
+
+/// Parses a case from its name, the same way as [`FromStr`].
+/// ```
+/// use convert_case::Case;
+///
+/// assert_eq!(Ok(Case::Kebab), Case::try_from("KeBAB"));
+/// assert!(Case::try_from("kebob").is_err());
+/// ```
+impl TryFrom<&str> for Case {
+    type Error = ParseCaseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// The error returned when parsing a [`Case`] from a string that doesn't name any case, or
+/// names a case from a feature that isn't enabled.
+/// ```
+/// use convert_case::Case;
+///
+/// let err = "snek".parse::<Case>().unwrap_err();
+/// assert_eq!("\"snek\" is not the name of a case", err.to_string());
+/// assert_eq!(None, err.required_feature());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCaseError {
+    input: String,
+    required_feature: Option<&'static str>,
+}
+
+impl ParseCaseError {
+    /// The feature that has to be enabled for the input to parse, like `"random"` for the
+    /// `Random` and `PseudoRandom` cases.
+    pub fn required_feature(&self) -> Option<&'static str> {
+        self.required_feature
+    }
+}
+
+impl fmt::Display for ParseCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.required_feature {
+            Some(feature) => write!(
+                f,
+                "\"{}\" names a case that requires the \"{}\" feature",
+                self.input, feature
+            ),
+            None => write!(f, "\"{}\" is not the name of a case", self.input),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCaseError {}
+
+/// Serializes a case as its name in snake case, like `"snake"` or `"upper_snake"`, which
+/// parses back into the same case with [`FromStr`].  Only available in the "serde" feature.
+/// ```
+/// use convert_case::Case;
+///
+/// assert_eq!("\"upper_kebab\"", serde_json::to_string(&Case::UpperKebab).unwrap());
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Case {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:?}", self).to_case(Case::Snake))
+    }
+}
+
+/// Deserializes a case from its name, accepting any name that [`FromStr`] does.  Only
+/// available in the "serde" feature.
+/// ```
+/// use convert_case::Case;
+///
+/// assert_eq!(Case::Camel, serde_json::from_str::<Case>("\"camel\"").unwrap());
+/// assert!(serde_json::from_str::<Case>("\"cammel\"").is_err());
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Case {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
 }
 
 #[cfg(test)]
@@ -398,4 +1033,413 @@ mod test {
             assert!(all.contains(&case));
         }
     }
+
+    #[test]
+    fn boundary_slices_match_delims() {
+        assert_eq!(vec![Boundary::from_delim("/")], Case::Path.boundaries());
+        assert_eq!(vec![Boundary::from_delim(".")], Case::Dot.boundaries());
+        assert_eq!(Some("/"), Case::Path.boundaries()[0].delim());
+        assert_eq!(Some("."), Case::Dot.boundaries()[0].delim());
+    }
+
+    #[test]
+    fn split_iter_uses_case_boundaries() {
+        let words: Vec<_> = Case::Camel.split_iter(&"userID2Name").collect();
+        assert_eq!(
+            crate::split("userID2Name", &Case::Camel.boundaries()),
+            words
+        );
+        assert_eq!(1, Case::Flat.split_iter(&"my_var").count());
+        assert_eq!(0, Case::Kebab.split_iter(&"--").count());
+    }
+
+    #[test]
+    fn difference_delim_only() {
+        let diff = Case::Snake.difference(&Case::Kebab);
+        assert!(diff.delim);
+        assert!(!diff.pattern);
+        assert!(!diff.boundaries);
+
+        assert_eq!(diff, Case::Path.difference(&Case::Snake));
+        assert_eq!(diff, Case::UpperSnake.difference(&Case::Cobol));
+    }
+
+    #[test]
+    fn difference_many() {
+        assert_eq!(
+            CaseDiff {
+                pattern: true,
+                delim: true,
+                boundaries: true
+            },
+            Case::Snake.difference(&Case::Camel)
+        );
+        assert_eq!(
+            CaseDiff {
+                pattern: true,
+                delim: false,
+                boundaries: false
+            },
+            Case::Lower.difference(&Case::Title)
+        );
+        assert_eq!(
+            CaseDiff {
+                pattern: false,
+                delim: true,
+                boundaries: false
+            },
+            Case::Snake.difference(&Case::Flat)
+        );
+    }
+
+    #[test]
+    fn difference_with_self() {
+        for case in Case::iter() {
+            assert!(case.difference(&case).is_identical());
+        }
+        assert!(Case::UpperSnake
+            .difference(&Case::ScreamingSnake)
+            .is_identical());
+    }
+
+    #[test]
+    fn parse_each_case() {
+        for case in Case::iter() {
+            assert_eq!(Ok(case), format!("{:?}", case).parse());
+        }
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for case in Case::all_cases() {
+            assert_eq!(Ok(case), case.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn display_names() {
+        assert_eq!("Camel", Case::Camel.to_string());
+        assert_eq!("Screaming Snake", format!("{}", Case::ScreamingSnake));
+        assert_eq!("Upper Flat", format!("{}", Case::UpperFlat));
+    }
+
+    #[test]
+    fn parse_aliases() {
+        assert_eq!(Ok(Case::Pascal), "Pascal".parse());
+        assert_eq!(Ok(Case::UpperCamel), "UpperCamel".parse());
+        assert_eq!(Ok(Case::UpperSnake), "UpperSnake".parse());
+        assert_eq!(Ok(Case::ScreamingSnake), "ScreamingSnake".parse());
+        assert_eq!(Ok(Case::Cobol), "cobol".parse());
+        assert_eq!(Ok(Case::UpperKebab), "upper_kebab".parse());
+    }
+
+    #[test]
+    fn parse_ignores_casing() {
+        assert_eq!(Ok(Case::Snake), "SNAKE".parse());
+        assert_eq!(Ok(Case::UpperFlat), "upperFlat".parse());
+        assert_eq!(Ok(Case::Alternating), "aLtErNaTiNg".parse());
+    }
+
+    #[test]
+    fn parse_ignores_separators() {
+        for name in [
+            "upper snake",
+            "upper-snake",
+            "upper_snake",
+            "UpperSnake",
+            "UPPERSNAKE",
+        ] {
+            assert_eq!(Ok(Case::UpperSnake), name.parse(), "{}", name);
+        }
+        assert_eq!(Ok(Case::UpperKebab), "upper kebab".parse());
+        assert_eq!(Ok(Case::UpperFlat), "Upper-Flat".parse());
+    }
+
+    #[test]
+    fn name_round_trip_every_case() {
+        for case in Case::all_cases() {
+            let parsed: Case = case.name().parse().unwrap();
+            assert_eq!(case.canonical(), parsed.canonical(), "{}", case.name());
+            assert_eq!(case, parsed);
+            let parsed: Case = format!("{:?}", case).parse().unwrap();
+            assert_eq!(case, parsed);
+        }
+    }
+
+    #[test]
+    fn aliases_behave_like_canonical_case() {
+        for case in Case::all_cases() {
+            assert!(
+                case.difference(&case.canonical()).is_identical(),
+                "{:?}",
+                case
+            );
+            assert_eq!(case.canonical(), case.canonical().canonical());
+        }
+        let aliases = Case::all_cases()
+            .into_iter()
+            .filter(|c| *c != c.canonical())
+            .count();
+        assert_eq!(3, aliases);
+    }
+
+    #[test]
+    fn aliases_map_to_listed_canonical_cases() {
+        let all = Case::all_cases();
+        for case in Case::all_cases_with_aliases() {
+            assert!(all.contains(case));
+            match case.is_alias() {
+                Some(canonical) => {
+                    assert_eq!(case.canonical(), canonical);
+                    assert!(all.contains(&canonical));
+                    assert_eq!(None, canonical.is_alias());
+                }
+                None => assert_eq!(*case, case.canonical()),
+            }
+        }
+        for case in Case::iter() {
+            assert!(Case::all_cases_with_aliases().contains(&case));
+        }
+    }
+
+    #[test]
+    fn parse_unknown_case() {
+        assert!("".parse::<Case>().is_err());
+        assert!("snake case".parse::<Case>().is_err());
+        assert!("upper-snake-camel".parse::<Case>().is_err());
+    }
+
+    #[cfg(not(feature = "random"))]
+    #[test]
+    fn parse_random_case_requires_feature() {
+        for name in ["random", "Pseudo Random", "pseudo_random"] {
+            let err = name.parse::<Case>().unwrap_err();
+            assert_eq!(Some("random"), err.required_feature());
+            assert!(
+                err.to_string().contains("requires the \"random\" feature"),
+                "{}",
+                err
+            );
+        }
+        assert_eq!(
+            None,
+            "randomish".parse::<Case>().unwrap_err().required_feature()
+        );
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn parse_random_case() {
+        assert_eq!(Ok(Case::Random), "random".parse());
+        assert_eq!(Ok(Case::PseudoRandom), "Pseudo Random".parse());
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(Ok(Case::Kebab), Case::try_from("KeBAB"));
+        assert_eq!(Ok(Case::UpperSnake), Case::try_from("upper_snake"));
+        assert_eq!(Ok(Case::ScreamingSnake), Case::try_from("screaming-snake"));
+        for case in Case::all_cases() {
+            assert_eq!(Ok(case), Case::try_from(case.to_string().as_str()));
+        }
+        let err = Case::try_from("snek").unwrap_err();
+        assert_eq!("\"snek\" is not the name of a case", err.to_string());
+    }
+
+    #[test]
+    fn matches_pattern_snake_and_kebab() {
+        for s in ["my_var", "my_var_2", "x", "2d_model", "über_größe"] {
+            assert!(Case::Snake.matches_pattern(s), "{}", s);
+        }
+        for s in [
+            "", "_my_var", "my_var_", "my__var", "my_Var", "my-var", "my var", "my_vär!",
+        ] {
+            assert!(!Case::Snake.matches_pattern(s), "{}", s);
+        }
+        assert!(Case::Kebab.matches_pattern("my-var-2"));
+        assert!(!Case::Kebab.matches_pattern("my_var"));
+        assert!(!Case::Kebab.matches_pattern("my--var"));
+        assert!(!Case::Kebab.matches_pattern("My-var"));
+    }
+
+    #[test]
+    fn matches_pattern_camel_and_pascal() {
+        for s in ["myVar", "myVar2", "xmlHTTPRequest", "x"] {
+            assert!(Case::Camel.matches_pattern(s), "{}", s);
+        }
+        for s in ["MyVar", "my_var", "my var", "2dModel", ""] {
+            assert!(!Case::Camel.matches_pattern(s), "{}", s);
+        }
+        for s in ["MyVar", "XMLHttpRequest", "X2"] {
+            assert!(Case::Pascal.matches_pattern(s), "{}", s);
+            assert!(Case::UpperCamel.matches_pattern(s), "{}", s);
+        }
+        for s in ["myVar", "My_Var", "My-Var"] {
+            assert!(!Case::Pascal.matches_pattern(s), "{}", s);
+        }
+    }
+
+    #[test]
+    fn matches_pattern_upper_snake_and_others() {
+        assert!(Case::UpperSnake.matches_pattern("MY_VAR_2"));
+        assert!(Case::ScreamingSnake.matches_pattern("MY_VAR"));
+        assert!(!Case::UpperSnake.matches_pattern("MY_var"));
+        assert!(!Case::UpperSnake.matches_pattern("MY__VAR"));
+        assert!(Case::Title.matches_pattern("My Var 2"));
+        assert!(!Case::Title.matches_pattern("My var"));
+        assert!(Case::Train.matches_pattern("My-Var"));
+        assert!(Case::Toggle.matches_pattern("mY vAR"));
+        assert!(Case::Flat.matches_pattern("myvar"));
+        assert!(!Case::UpperFlat.matches_pattern("MYvAR"));
+        assert!(Case::Alternating.matches_pattern("mY vAr"));
+        assert!(!Case::Alternating.matches_pattern("my var"));
+    }
+
+    #[test]
+    fn invert_casing_pairs() {
+        use Case::*;
+        let pairs = [
+            (Snake, UpperSnake),
+            (Kebab, Cobol),
+            (Flat, UpperFlat),
+            (Lower, Upper),
+            (Camel, Pascal),
+        ];
+        for (lower, upper) in pairs {
+            assert_eq!(upper, lower.invert_casing());
+            assert_eq!(lower, upper.invert_casing());
+            assert_eq!(lower.delim(), upper.delim());
+        }
+        assert_eq!(Snake, ScreamingSnake.invert_casing());
+        assert_eq!(Kebab, UpperKebab.invert_casing());
+        assert_eq!(Camel, UpperCamel.invert_casing());
+        for case in [Title, Toggle, Train, Path, Dot, Alternating] {
+            assert_eq!(case, case.invert_casing());
+        }
+    }
+
+    #[test]
+    fn detect_all_lowercase_word() {
+        use Case::*;
+        assert_eq!(
+            vec![Lower, Camel, Snake, Kebab, Path, Dot, Flat],
+            Case::detect_all("asef")
+        );
+        assert_eq!(vec![Camel], Case::detect_all("asefCase"));
+    }
+
+    #[test]
+    fn detect_prefers_most_words() {
+        assert_eq!(Some(Case::Lower), Case::detect("asef"));
+        assert_eq!(Some(Case::Camel), Case::detect("asefCase"));
+        assert_eq!(Some(Case::Kebab), Case::detect("my-var"));
+        assert_eq!(Some(Case::Title), Case::detect("My Var"));
+        assert_eq!(Some(Case::UpperSnake), Case::detect("MY_VAR"));
+        assert_eq!(Some(Case::Upper), Case::detect("MY VAR"));
+        assert_eq!(Some(Case::Path), Case::detect("src/lib"));
+        assert_eq!(Some(Case::Dot), Case::detect("server.http.port"));
+    }
+
+    #[test]
+    fn detect_each_case() {
+        let s = "My String Identifier";
+        for case in Case::deterministic_cases() {
+            let converted = s.to_case(case);
+            assert!(Case::detect_all(&converted).contains(&case));
+            assert!(Case::detect(&converted).is_some());
+        }
+    }
+
+    #[test]
+    fn detect_none() {
+        assert_eq!(None, Case::detect("my Var_name"));
+        assert!(Case::detect_all("my Var_name").is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        for case in Case::deterministic_cases() {
+            let json = serde_json::to_string(&case).unwrap();
+            assert_eq!(case, serde_json::from_str::<Case>(&json).unwrap());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_names() {
+        assert_eq!("\"snake\"", serde_json::to_string(&Case::Snake).unwrap());
+        assert_eq!(
+            "\"screaming_snake\"",
+            serde_json::to_string(&Case::ScreamingSnake).unwrap()
+        );
+        assert_eq!(
+            vec![Case::Kebab, Case::UpperFlat],
+            serde_json::from_str::<Vec<Case>>(r#"["kebab", "UpperFlat"]"#).unwrap()
+        );
+        assert!(serde_json::from_str::<Case>("\"snek\"").is_err());
+    }
+
+    #[test]
+    fn suggest_typos() {
+        assert_eq!(Some(Case::Camel), Case::suggest("cammel"));
+        assert_eq!(Some(Case::Snake), Case::suggest("snak"));
+        assert_eq!(Some(Case::Kebab), Case::suggest("Kebab"));
+        assert_eq!(Some(Case::Pascal), Case::suggest("paskal"));
+        assert_eq!(None, Case::suggest("nothing like it"));
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(0, edit_distance("", ""));
+        assert_eq!(3, edit_distance("", "abc"));
+        assert_eq!(3, edit_distance("kitten", "sitting"));
+        assert_eq!(1, edit_distance("cammel", "camel"));
+        assert_eq!(1, edit_distance("é", "e"));
+    }
+
+    #[test]
+    fn with_acronyms() {
+        let pascal = Case::Pascal.with_acronyms(&["io", "xml", "http"]);
+        assert_eq!("IOStream", pascal.convert("io_stream"));
+        assert_eq!("HTTPServerIO", pascal.convert("http server io"));
+
+        let camel = Case::Camel.with_acronyms(&["io"]);
+        assert_eq!("ioStreamIO", camel.convert("io_stream_io"));
+
+        let snake = Case::Snake.with_acronyms(&["io"]);
+        assert_eq!("io_stream", snake.convert("IOStream"));
+    }
+
+    #[test]
+    fn owned_case_from_case() {
+        for case in Case::deterministic_cases() {
+            let owned = OwnedCase::from(case);
+            for s in ["myVarName", "MY_VAR name", "my-var/name 2"] {
+                assert_eq!(
+                    s.from_case(case).to_case(case),
+                    owned.converter().convert(s)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn path_case() {
+        assert_eq!("my/module/name", "MyModuleName".to_case(Case::Path));
+        assert_eq!(
+            "com/xenoterracide",
+            "com.xenoterracide"
+                .with_boundaries(&[Boundary::from_delim(".")])
+                .to_case(Case::Path)
+        );
+        assert_eq!(
+            "lib_v2/utf8/mod",
+            "lib_v2/utf8/mod".from_case(Case::Path).to_case(Case::Path)
+        );
+        assert_eq!(
+            "Utf8V2Mod",
+            "utf8/v2/mod".from_case(Case::Path).to_case(Case::Pascal)
+        );
+    }
 }
@@ -1,8 +1,25 @@
-use crate::segmentation;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::pattern::{self, CaseMapping};
+use crate::segmentation::{BoundaryFn, Words};
 use crate::Boundary;
 use crate::Case;
+use crate::Locale;
+use crate::OwnedCase;
 use crate::Pattern;
 
+type Joiner = Arc<dyn Fn(&[String]) -> String + Send + Sync>;
+type PatternFn = Arc<dyn Fn(&[&str]) -> Vec<String> + Send + Sync>;
+
 /// The parameters for performing a case conversion.
 ///
 /// A `Converter` stores three fields needed for case conversion.
@@ -54,6 +71,19 @@ use crate::Pattern;
 ///     .set_delim(".");
 /// assert_eq!("collision.Shape.2d", dot_camel.convert("CollisionShape2D"));
 /// ```
+///
+/// A `Converter` can be cloned to reuse one configuration for several conversions.
+///
+/// ```
+/// use convert_case::{Case, Converter};
+///
+/// let base = Converter::new().from_case(Case::Camel);
+/// let snake = base.clone().to_case(Case::Snake);
+/// let kebab = base.to_case(Case::Kebab);
+/// assert_eq!("my_var_name", snake.convert("myVarName"));
+/// assert_eq!("my-var-name", kebab.convert("myVarName"));
+/// ```
+#[derive(Clone)]
 pub struct Converter {
     /// How a string is segmented into words.
     pub boundaries: Vec<Boundary>,
@@ -65,18 +95,169 @@ pub struct Converter {
 
     /// The string used to join mutated words together.
     pub delim: String,
+
+    // Boundaries backed by closures, checked after `boundaries`.
+    boundary_fns: Vec<BoundaryFn>,
+
+    // Treats spans enclosed in double quotes as single words that are never mutated.
+    preserve_quoted: bool,
+
+    // Treats numeric literals as single words.
+    keep_numbers: bool,
+
+    // Keeps numbers whole like `keep_numbers`, without signs, also allowing underscores
+    // between digits.
+    number_aware: bool,
+
+    // Removes characters that aren't letters or digits from each word.
+    strip_symbols: bool,
+
+    // Mutates words in place of the pattern.
+    pattern_fn: Option<PatternFn>,
+
+    // Joins mutated words in place of the delimiter.
+    joiner: Option<Joiner>,
+
+    // Canonical spellings of acronyms that replace capitalized words after the pattern is
+    // applied.
+    acronyms: Vec<String>,
+
+    // Keeps words that were all uppercase in the input where the pattern capitalizes them.
+    preserve_acronyms: bool,
+
+    // Words that are always lowercased or uppercased after the pattern is applied.
+    lower_words: Vec<String>,
+    upper_words: Vec<String>,
+
+    // Only lowercases the first letter of the first word when the pattern is camel.
+    camel_lower_first_letter_only: bool,
+
+    // Uppercases letters that follow a digit after the pattern is applied.
+    capitalize_after_digits: bool,
+
+    // Case mappings used by the pattern.
+    mapping: CaseMapping,
+
+    // Splits runs of uppercase letters that aren't followed by a lowercase letter into
+    // single letters.
+    split_all_caps: bool,
+
+    // Returns the original string when the converted string is empty.
+    fallback_to_input: bool,
+
+    // Wraps the result in double quotes when it isn't a plain SQL identifier.
+    sql_identifier: bool,
+
+    // Keeps whitespace at the start and end of the string as it is.
+    preserve_edge_whitespace: bool,
+
+    // Keeps one delimiter from the start and end of the string.
+    preserve_affixes: bool,
+
+    // Joins a number at the start of the string to the word right after it.
+    keep_leading_number: bool,
+
+    // Capitalizes the first word and each word after one ending with a period.
+    sentence_per_period: bool,
+
+    // Prefixes removed from the start of the string when a capitalized word follows them.
+    type_prefixes: Vec<String>,
+
+    // Removes a leading `k` followed by an uppercase letter, as in `kMaxValue`.
+    strip_k_prefix: bool,
+
+    // Shortest run of uppercase letters the acronym boundary splits, unless it is a known
+    // acronym.
+    acronym_min_len: usize,
+
+    // Keeps at most this many words, followed by `and N more` for the words dropped.
+    max_words: Option<usize>,
+
+    // Seeds the random number generator used by the random patterns.
+    #[cfg(feature = "random")]
+    seed: Option<u64>,
+
+    // Removes combining marks from decomposed characters after the pattern is applied.
+    #[cfg(feature = "diacritics")]
+    strip_diacritics: bool,
+}
+
+impl fmt::Debug for Converter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Converter")
+            .field("boundaries", &self.boundaries)
+            .field("pattern", &self.pattern)
+            .field("delim", &self.delim)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for Converter {
     fn default() -> Self {
         Converter {
-            boundaries: Boundary::defaults(),
+            boundaries: Boundary::defaults().to_vec(),
             pattern: None,
             delim: String::new(),
+            boundary_fns: Vec::new(),
+            preserve_quoted: false,
+            keep_numbers: false,
+            number_aware: false,
+            strip_symbols: false,
+            pattern_fn: None,
+            joiner: None,
+            acronyms: Vec::new(),
+            preserve_acronyms: false,
+            lower_words: Vec::new(),
+            upper_words: Vec::new(),
+            camel_lower_first_letter_only: false,
+            capitalize_after_digits: false,
+            mapping: CaseMapping::default(),
+            split_all_caps: false,
+            fallback_to_input: false,
+            sql_identifier: false,
+            preserve_edge_whitespace: false,
+            preserve_affixes: false,
+            keep_leading_number: false,
+            sentence_per_period: false,
+            type_prefixes: Vec::new(),
+            strip_k_prefix: false,
+            acronym_min_len: 0,
+            max_words: None,
+            #[cfg(feature = "random")]
+            seed: None,
+            #[cfg(feature = "diacritics")]
+            strip_diacritics: false,
         }
     }
 }
 
+/// Creates a converter into the case, the same as `Converter::new().to_case(case)`.
+/// ```
+/// use convert_case::{Case, Converter};
+///
+/// let conv: Converter = Case::Snake.into();
+/// assert_eq!("my_var_name", conv.convert("myVarName"));
+/// ```
+impl From<Case> for Converter {
+    fn from(case: Case) -> Self {
+        Converter::new().to_case(case)
+    }
+}
+
+/// Creates a converter from the first case into the second, the same as
+/// `Converter::new().from_case(from).to_case(to)`.
+/// ```
+/// use convert_case::{Case, Converter};
+///
+/// let conv = Converter::from((Case::Kebab, Case::Snake));
+/// assert_eq!("my_varname", conv.convert("my-varName"));
+/// ```
+impl From<(Case, Case)> for Converter {
+    fn from((from, to): (Case, Case)) -> Self {
+        Converter::new().from_case(from).to_case(to)
+    }
+}
+
 impl Converter {
     /// Creates a new `Converter` with default fields.  This is the same as `Default::default()`.
     /// The `Converter` will use `Boundary::defaults()` for boundaries, no pattern, and an empty
@@ -103,13 +284,583 @@ impl Converter {
     where
         T: AsRef<str>,
     {
-        let words = segmentation::split(&s, &self.boundaries);
-        if let Some(p) = self.pattern {
-            let words = words.iter().map(|s| s.as_ref()).collect::<Vec<&str>>();
-            p.mutate(&words).join(&self.delim)
+        let mut buf = String::new();
+        self.convert_into(s, &mut buf);
+        buf
+    }
+
+    /// Converts a string, but borrows `s` when converting doesn't change it.
+    /// ```
+    /// use std::borrow::Cow;
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Flat);
+    /// assert!(matches!(conv.convert_cow("foobar"), Cow::Borrowed("foobar")));
+    /// assert!(matches!(conv.convert_cow("fooBar"), Cow::Owned(_)));
+    /// ```
+    pub fn convert_cow<'s>(&self, s: &'s str) -> Cow<'s, str> {
+        let converted = self.convert(s);
+        if converted == s {
+            Cow::Borrowed(s)
+        } else {
+            Cow::Owned(converted)
+        }
+    }
+
+    /// Converts every string in `inputs`, returning the results in the same order.  The words
+    /// of each string are split into one scratch vector and joined into one scratch string,
+    /// so only the results are allocated for each string.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// assert_eq!(
+    ///     vec!["first_name", "last_name"],
+    ///     conv.convert_all(["firstName", "LastName"])
+    /// );
+    /// ```
+    pub fn convert_all<I, S>(&self, inputs: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let inputs: Vec<S> = inputs.into_iter().collect();
+        let mut words = Vec::new();
+        let mut buf = String::new();
+        inputs
+            .iter()
+            .map(|s| {
+                self.convert_with(s.as_ref(), &mut words, &mut buf);
+                buf.clone()
+            })
+            .collect()
+    }
+
+    /// Converts a string and writes the result into `buf`, reusing its allocation.  The
+    /// buffer is cleared before the converted string is written.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// let mut buf = String::from("leftover");
+    /// conv.convert_into("XmlHttpRequest", &mut buf);
+    /// assert_eq!("xml_http_request", buf);
+    /// ```
+    pub fn convert_into<T>(&self, s: T, buf: &mut String)
+    where
+        T: AsRef<str>,
+    {
+        self.convert_with(s.as_ref(), &mut Vec::new(), buf);
+    }
+
+    /// Converts `s` into `buf`, using `words` as scratch space for its words.
+    fn convert_with<'s>(&self, s: &'s str, words: &mut Vec<&'s str>, buf: &mut String) {
+        buf.clear();
+        let (_, inner, _) = self.edge_whitespace(s);
+        self.split_into(inner, words);
+        self.join_into(&self.mutate(words), buf);
+        self.finish(s, buf);
+    }
+
+    /// Applies the pattern to words that are already split and joins them with the delimiter,
+    /// without looking for boundaries.  This is useful when words come from another tokenizer.
+    /// Options that change words, like [`with_acronyms`](Converter::with_acronyms), still
+    /// apply.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Camel);
+    /// assert_eq!("getTotalLength", conv.join_words(&["get", "total", "length"]));
+    /// assert_eq!("versionV2", conv.join_words(&["version", "v2"]));
+    /// ```
+    pub fn join_words(&self, words: &[&str]) -> String {
+        let mut joined = String::new();
+        self.join_into(&self.mutate(words), &mut joined);
+        joined
+    }
+
+    /// Converts `s` in place, keeping its allocation.  The words of `s` are borrowed while
+    /// converting, so the result is built in a temporary string and then copied back into
+    /// `s`.  This still allocates once per call, but `s` keeps its capacity, so a string that
+    /// is reused for many conversions of similar length doesn't have to grow again.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// let mut s = String::from("XmlHttpRequest");
+    /// conv.convert_in_place(&mut s);
+    /// assert_eq!("xml_http_request", s);
+    /// ```
+    pub fn convert_in_place(&self, s: &mut String) {
+        let converted = self.convert(s.as_str());
+        s.clear();
+        s.push_str(&converted);
+    }
+
+    /// Converts a string and appends the UTF-8 bytes of the result to `out`, without building
+    /// an intermediate `String`.  Unlike [`convert_into`](Converter::convert_into), existing
+    /// contents of `out` are kept.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Kebab);
+    /// let mut out = b"name=".to_vec();
+    /// conv.convert_to_bytes("XmlHttpRequest", &mut out);
+    /// assert_eq!(b"name=xml-http-request", out.as_slice());
+    /// ```
+    pub fn convert_to_bytes(&self, s: &str, out: &mut Vec<u8>) {
+        if self.sql_identifier || self.preserve_edge_whitespace || self.preserve_affixes {
+            out.extend_from_slice(self.convert(s).as_bytes());
+            return;
+        }
+        let len = out.len();
+        let words = self.split(s);
+        self.join_with(&self.mutate(&words), |part| {
+            out.extend_from_slice(part.as_bytes())
+        });
+        if self.fallback_to_input && out.len() == len {
+            out.extend_from_slice(s.as_bytes());
+        }
+    }
+
+    /// Converts a string and returns the mutated words along with the joined result, so
+    /// callers that need both don't have to split the string twice.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Kebab);
+    /// let (words, joined) = conv.convert_detailed("XmlHttpRequest");
+    /// assert_eq!(vec!["xml", "http", "request"], words);
+    /// assert_eq!("xml-http-request", joined);
+    /// ```
+    pub fn convert_detailed(&self, s: &str) -> (Vec<String>, String) {
+        let (_, inner, _) = self.edge_whitespace(s);
+        let words = self.mutate(&self.split(inner));
+        let mut joined = String::new();
+        self.join_into(&words, &mut joined);
+        self.finish(s, &mut joined);
+        (words, joined)
+    }
+
+    /// Converts a string from a source case and returns the result along with the source
+    /// case, so tools can record how each string was read.  If `from` is `None`, the source
+    /// case is found with [`Case::detect`].  The string is split with the boundaries of the
+    /// source case instead of this converter's boundaries.  If `from` is `None` and no case
+    /// is detected, the converter's own boundaries are used and no case is returned.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// assert_eq!(
+    ///     ("get_user_name".to_string(), Some(Case::Camel)),
+    ///     conv.convert_annotated("getUserName", None)
+    /// );
+    /// assert_eq!(
+    ///     ("getuser_name".to_string(), Some(Case::Snake)),
+    ///     conv.convert_annotated("getUser_name", Some(Case::Snake))
+    /// );
+    /// ```
+    pub fn convert_annotated(&self, s: &str, from: Option<Case>) -> (String, Option<Case>) {
+        match from.or_else(|| Case::detect(s)) {
+            Some(case) => (self.clone().from_case(case).convert(s), Some(case)),
+            None => (self.convert(s), None),
+        }
+    }
+
+    /// Returns each word of `s` paired with the word it is converted into, without joining
+    /// them, so the change to each word can be shown before it is applied.  Every word that
+    /// `s` is split into is listed once.  A word that
+    /// [`strip_symbols`](Converter::strip_symbols) removes entirely is paired with an empty
+    /// string.  [`summarize`](Converter::summarize) isn't applied, since the words it adds
+    /// don't come from `s`.  If a [pattern closure](Converter::set_pattern_closure) returns
+    /// a different number of words than it is given, its words are paired by position, and
+    /// words left without a pair get an empty string.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Camel);
+    /// assert_eq!(
+    ///     vec![
+    ///         ("get".to_string(), "get".to_string()),
+    ///         ("HTTP".to_string(), "Http".to_string()),
+    ///         ("Response".to_string(), "Response".to_string()),
+    ///     ],
+    ///     conv.preview("getHTTPResponse")
+    /// );
+    /// ```
+    pub fn preview(&self, s: &str) -> Vec<(String, String)> {
+        let (_, inner, _) = self.edge_whitespace(s);
+        let words = self.split(inner);
+        let stripped: Vec<String> = words
+            .iter()
+            .map(|word| {
+                if self.strip_symbols {
+                    self.strip_word(word)
+                } else {
+                    word.to_string()
+                }
+            })
+            .collect();
+        let kept: Vec<&str> = stripped
+            .iter()
+            .map(String::as_str)
+            .filter(|word| !word.is_empty())
+            .collect();
+        let mut mutated = self.mutate_words(&kept).into_iter();
+        words
+            .iter()
+            .zip(&stripped)
+            .map(|(word, stripped)| {
+                let new = if stripped.is_empty() {
+                    String::new()
+                } else {
+                    mutated.next().unwrap_or_default()
+                };
+                (word.to_string(), new)
+            })
+            .collect()
+    }
+
+    /// Converts the file name of `path`, keeping its parent directory.  The name is split on
+    /// dots, and only the part before the first dot is converted, unless `include_ext` is true,
+    /// in which case each extension is converted as well.  Leading dots, as in `.bashrc`, are
+    /// kept as they are.  A path without a file name, or whose file name isn't valid UTF-8,
+    /// is returned unchanged.
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Title);
+    /// assert_eq!(
+    ///     PathBuf::from("music/Blue Oyster.cult.gdz"),
+    ///     conv.convert_path_filename(Path::new("music/blue-oyster.cult.gdz"), false)
+    /// );
+    /// assert_eq!(
+    ///     PathBuf::from("music/Blue Oyster.Cult.Gdz"),
+    ///     conv.convert_path_filename(Path::new("music/blue-oyster.cult.gdz"), true)
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn convert_path_filename(&self, path: &Path, include_ext: bool) -> PathBuf {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            return path.to_path_buf();
+        };
+        let unprefixed = name.trim_start_matches('.');
+        let mut new_name = name[..name.len() - unprefixed.len()].to_string();
+        for (i, part) in unprefixed.split('.').enumerate() {
+            if i > 0 {
+                new_name.push('.');
+            }
+            if i == 0 || include_ext {
+                new_name.push_str(&self.convert(part));
+            } else {
+                new_name.push_str(part);
+            }
+        }
+        path.with_file_name(new_name)
+    }
+
+    /// Segments `s` into words, keeping quoted spans whole if `preserve_quoted` is set and
+    /// numbers whole if `keep_numbers` is set.
+    pub(crate) fn split<'s>(&self, s: &'s str) -> Vec<&'s str> {
+        let mut words = Vec::new();
+        self.split_into(s, &mut words);
+        words
+    }
+
+    /// Clears `words` and segments `s` into it, like [`split`](Converter::split).
+    fn split_into<'s>(&self, s: &'s str, words: &mut Vec<&'s str>) {
+        words.clear();
+        let s = self.strip_type_prefix(s);
+        let mut pos = 0;
+        while let Some((start, end)) = self.protected_span(s, pos) {
+            self.push_words(&s[pos..start], words);
+            words.push(&s[start..end]);
+            pos = end;
+        }
+        self.push_words(&s[pos..], words);
+        if self.keep_leading_number {
+            join_leading_number(s, words);
+        }
+    }
+
+    /// Removes the first of `type_prefixes` that starts `s` and is followed by a capitalized
+    /// word, meaning an uppercase letter and then a lowercase letter.
+    fn strip_type_prefix<'s>(&self, s: &'s str) -> &'s str {
+        if self.strip_k_prefix {
+            if let Some(rest) = s.strip_prefix('k') {
+                if rest.chars().next().is_some_and(char::is_uppercase) {
+                    return rest;
+                }
+            }
+        }
+        self.type_prefixes
+            .iter()
+            .filter_map(|prefix| s.strip_prefix(prefix.as_str()))
+            .find(|rest| {
+                let mut chars = rest.chars();
+                chars.next().is_some_and(char::is_uppercase)
+                    && chars.next().is_some_and(char::is_lowercase)
+            })
+            .unwrap_or(s)
+    }
+
+    /// Pushes the words of `s` onto `words`, splitting all caps words into letters if
+    /// `split_all_caps` is set.
+    fn push_words<'s>(&self, s: &'s str, words: &mut Vec<&'s str>) {
+        if !self.split_all_caps {
+            words.extend(self.words(s));
+            return;
+        }
+        for word in self.words(s) {
+            let end = word.as_ptr() as usize - s.as_ptr() as usize + word.len();
+            if is_all_caps(word) && !starts_with_lowercase_run(&s[end..]) {
+                words.extend(word.graphemes(true));
+            } else {
+                words.push(word);
+            }
+        }
+    }
+
+    /// Applies the pattern, seeding it if a seed was set, and only lowercasing the first letter
+    /// of camel case if `camel_lower_first_letter_only` is set.
+    fn apply_pattern(&self, pattern: Pattern, words: &[&str]) -> Vec<String> {
+        if self.camel_lower_first_letter_only && pattern == Pattern::Camel {
+            return pattern::camel_lower_first_letter_only(words, self.mapping);
+        }
+        #[cfg(feature = "random")]
+        if let Some(seed) = self.seed {
+            return pattern.mutate_seeded(words, seed, self.mapping);
+        }
+        pattern.mutate_in(words, self.mapping)
+    }
+
+    /// Returns the canonical spelling of a capitalized word if it is one of the acronyms.
+    fn acronym_for(&self, word: &str) -> Option<&str> {
+        if !pattern::is_capitalized(word) {
+            return None;
+        }
+        let word = word.to_lowercase();
+        self.acronyms
+            .iter()
+            .find(|acronym| acronym.to_lowercase() == word)
+            .map(String::as_str)
+    }
+
+    /// Finds the first span at or after `from` that should be kept as a single word.
+    fn protected_span(&self, s: &str, from: usize) -> Option<(usize, usize)> {
+        let quoted = self
+            .preserve_quoted
+            .then(|| quoted_span(&s[from..]).map(|(start, end)| (from + start, from + end)))
+            .flatten();
+        let number = (self.keep_numbers || self.number_aware)
+            .then(|| number_span(s, from, self.keep_numbers, self.number_aware))
+            .flatten();
+        match (quoted, number) {
+            (Some(q), Some(n)) => Some(if n.0 < q.0 { n } else { q }),
+            (q, n) => q.or(n),
+        }
+    }
+
+    /// Segments `s` on both the boundaries and the boundary closures.
+    fn words<'s>(&self, s: &'s str) -> Words<'s, '_> {
+        Words::new(s, Cow::Borrowed(&self.boundaries))
+            .with_boundary_fns(&self.boundary_fns)
+            .with_acronym_rules(self.acronym_min_len, &self.acronyms)
+    }
+
+    /// Splits the whitespace at the start and end of `s` from the rest of it, if it is kept
+    /// with `preserve_edge_whitespace`.
+    fn edge_whitespace<'s>(&self, s: &'s str) -> (&'s str, &'s str, &'s str) {
+        if !self.preserve_edge_whitespace {
+            return ("", s, "");
+        }
+        let rest = s.trim_start();
+        let inner = rest.trim_end();
+        (&s[..s.len() - rest.len()], inner, &rest[inner.len()..])
+    }
+
+    /// The delimiters that `s` starts and ends with, if any.  Only one delimiter is taken from
+    /// each end, and the same delimiter isn't counted as both.
+    fn affixes<'s>(&self, s: &'s str) -> (&'s str, &'s str) {
+        let delim_at = |found: &dyn Fn(&str) -> bool| {
+            self.boundaries
+                .iter()
+                .filter_map(Boundary::delim)
+                .find(|delim| found(delim))
+                .unwrap_or("")
+        };
+        let prefix = delim_at(&|delim| s.starts_with(delim));
+        let rest = &s[prefix.len()..];
+        let suffix = delim_at(&|delim| rest.ends_with(delim));
+        (&s[..prefix.len()], &rest[rest.len() - suffix.len()..])
+    }
+
+    /// Applies the options that act on the whole converted string `joined`, given the
+    /// string `s` it was converted from.
+    fn finish(&self, s: &str, joined: &mut String) {
+        let (leading, inner, trailing) = self.edge_whitespace(s);
+        if self.fallback_to_input && joined.is_empty() {
+            joined.push_str(inner);
+        } else if self.preserve_affixes {
+            let (prefix, suffix) = self.affixes(inner);
+            joined.insert_str(0, prefix);
+            joined.push_str(suffix);
+        }
+        if self.sql_identifier && needs_sql_quotes(joined) {
+            *joined = quote_sql_identifier(joined);
+        }
+        joined.insert_str(0, leading);
+        joined.push_str(trailing);
+    }
+
+    /// Joins mutated words into `buf` with the joiner if one is set, otherwise the delimiter.
+    fn join_into(&self, words: &[String], buf: &mut String) {
+        self.join_with(words, |part| buf.push_str(part));
+    }
+
+    /// Passes each piece of the joined result to `push`, in order.
+    fn join_with<F>(&self, words: &[String], mut push: F)
+    where
+        F: FnMut(&str),
+    {
+        if let Some(joiner) = &self.joiner {
+            push(&joiner(words));
+            return;
+        }
+        for (i, word) in words.iter().enumerate() {
+            if i > 0 {
+                push(&self.delim);
+            }
+            push(word);
+        }
+    }
+
+    /// Removes symbols from each word if `strip_symbols` is set, then mutates the words.
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        let more;
+        let mut summary;
+        let words = match self.max_words {
+            Some(max) if words.len() > max => {
+                more = (words.len() - max).to_string();
+                summary = words[..max].to_vec();
+                summary.extend(["and", more.as_str(), "more"]);
+                &summary[..]
+            }
+            _ => words,
+        };
+        if !self.strip_symbols {
+            return self.mutate_words(words);
+        }
+        let stripped: Vec<String> = words.iter().map(|word| self.strip_word(word)).collect();
+        let stripped: Vec<&str> = stripped
+            .iter()
+            .map(String::as_str)
+            .filter(|word| !word.is_empty())
+            .collect();
+        self.mutate_words(&stripped)
+    }
+
+    /// Removes the symbols from `word`, unless it is quoted and `preserve_quoted` is set.
+    fn strip_word(&self, word: &str) -> String {
+        if self.preserve_quoted && is_quoted(word) {
+            word.to_string()
         } else {
-            words.join(&self.delim)
+            pattern::strip_symbols(word)
+        }
+    }
+
+    /// Applies the pattern to each word, leaving quoted words untouched if `preserve_quoted`
+    /// is set.
+    fn mutate_words(&self, words: &[&str]) -> Vec<String> {
+        let is_kept = |word: &&str| self.preserve_quoted && is_quoted(word);
+        // where each quoted word ends up in `mutated`, so it can be restored at the end
+        let mut quoted: Vec<(usize, &str)> = Vec::new();
+        let mut mutated = match (&self.pattern_fn, self.pattern) {
+            (Some(pattern_fn), _) => {
+                // a closure can change the number of words, so it never sees the quoted words
+                // and they are put back at their index afterwards
+                let unquoted: Vec<&str> = words.iter().copied().filter(|w| !is_kept(w)).collect();
+                let mut mutated = pattern_fn(&unquoted);
+                for (i, word) in words.iter().enumerate().filter(|(_, w)| is_kept(w)) {
+                    let at = i.min(mutated.len());
+                    mutated.insert(at, word.to_string());
+                    quoted.push((at, word));
+                }
+                mutated
+            }
+            (None, Some(p)) => self.apply_pattern(p, words),
+            (None, None) => words.iter().map(|w| w.to_string()).collect(),
+        };
+        if self.pattern_fn.is_none() {
+            quoted = words
+                .iter()
+                .copied()
+                .enumerate()
+                .filter(|(_, w)| is_kept(w))
+                .collect();
+        }
+        if !self.acronyms.is_empty() {
+            for word in mutated.iter_mut() {
+                if let Some(acronym) = self.acronym_for(word) {
+                    *word = acronym.to_string();
+                }
+            }
+        }
+        // words can only be matched with the input when there is one for each, which a
+        // pattern closure doesn't have to keep
+        if self.preserve_acronyms && mutated.len() == words.len() {
+            for (new, old) in mutated.iter_mut().zip(words) {
+                if is_all_caps(old) && pattern::is_capitalized(new) {
+                    *new = old.to_string();
+                }
+            }
+        }
+        if !self.lower_words.is_empty() || !self.upper_words.is_empty() {
+            for word in mutated.iter_mut() {
+                let lower = word.to_lowercase();
+                if self.lower_words.contains(&lower) {
+                    *word = lower;
+                } else if self.upper_words.contains(&lower) {
+                    *word = word.to_uppercase();
+                }
+            }
+        }
+        if self.capitalize_after_digits {
+            for word in mutated.iter_mut() {
+                *word = pattern::capitalize_after_digits(word);
+            }
         }
+        if self.sentence_per_period {
+            pattern::capitalize_sentences(&mut mutated);
+        }
+        #[cfg(feature = "diacritics")]
+        if self.strip_diacritics {
+            for word in mutated.iter_mut() {
+                *word = pattern::strip_diacritics(word);
+            }
+        }
+        for (at, word) in quoted {
+            mutated[at] = word.to_string();
+        }
+        mutated
+    }
+
+    /// Creates a `Converter` that only changes the delimiter between words to that of `case`,
+    /// keeping the case of every letter.  Words are split on [`Boundary::delims`], since the
+    /// boundaries between lowercase and uppercase letters would break apart words whose case
+    /// is being kept.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::preserve_case(Case::Kebab);
+    /// assert_eq!("wIErd-CASing", conv.convert("wIErd CASing"));
+    /// assert_eq!("XMLHttp-request", conv.convert("XMLHttp_request"));
+    /// ```
+    pub fn preserve_case(case: Case) -> Self {
+        Converter::new()
+            .set_boundaries(&Boundary::delims())
+            .set_delim(case.delim())
     }
 
     /// Set the pattern and delimiter to those associated with the given case.
@@ -126,6 +877,20 @@ impl Converter {
         self
     }
 
+    /// Set the pattern and delimiter to those of the given [`OwnedCase`].
+    /// ```
+    /// use convert_case::{Case, Converter, OwnedCase};
+    ///
+    /// let kebab = OwnedCase::from(Case::Kebab);
+    /// let conv = Converter::new().to_owned_case(&kebab);
+    /// assert_eq!("my-var-name", conv.convert("MyVarName"));
+    /// ```
+    pub fn to_owned_case(mut self, case: &OwnedCase) -> Self {
+        self.pattern = case.pattern;
+        self.delim = case.delim.clone();
+        self
+    }
+
     /// Sets the boundaries to those associated with the provided case.  This is used
     /// by the `from_case` function in the `Casing` trait.
     /// ```
@@ -215,6 +980,35 @@ impl Converter {
         self
     }
 
+    /// Adds a boundary backed by a closure, for splitting on something that is only known at
+    /// runtime.  The closure is given the rest of the string starting at each grapheme, and
+    /// returns the number of bytes to consume if the string should be split there, or `None`.
+    /// Returning `Some(0)` splits without consuming anything.
+    ///
+    /// Boundary closures are checked after the boundaries in [`boundaries`](Converter::boundaries),
+    /// and are kept when the boundaries are replaced by methods like `set_boundaries`.
+    /// ```
+    /// use std::collections::HashSet;
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let separators: HashSet<String> = ["::", "->"].iter().map(|s| s.to_string()).collect();
+    /// let conv = Converter::new()
+    ///     .add_boundary_fn(move |s| {
+    ///         let sep = separators.iter().find(|sep| s.starts_with(sep.as_str()))?;
+    ///         Some(sep.len())
+    ///     })
+    ///     .set_boundaries(&[])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("module_item_field", conv.convert("module::item->field"));
+    /// ```
+    pub fn add_boundary_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) -> Option<usize> + Send + Sync + 'static,
+    {
+        self.boundary_fns.push(Arc::new(f));
+        self
+    }
+
     /// Sets the delimeter.
     /// ```
     /// use convert_case::{Case, Converter};
@@ -275,105 +1069,1725 @@ impl Converter {
         self.pattern = None;
         self
     }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::Casing;
-    use crate::Pattern;
 
-    #[test]
-    fn snake_converter_from_case() {
-        let conv = Converter::new().to_case(Case::Snake);
-        let s = String::from("my var name");
-        assert_eq!(s.to_case(Case::Snake), conv.convert(s));
+    /// Sets the pattern to [`Uppercase`](Pattern::Uppercase), keeping the delimiter.  This
+    /// gives the upper case version of a case that has no upper case variant.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Dot).screaming();
+    /// assert_eq!("SERVER.HTTP.PORT", conv.convert("server http port"));
+    /// ```
+    pub fn screaming(self) -> Self {
+        self.set_pattern(Pattern::Uppercase)
     }
 
-    #[test]
-    fn snake_converter_from_scratch() {
-        let conv = Converter::new()
-            .set_delim("_")
-            .set_pattern(Pattern::Lowercase);
-        let s = String::from("my var name");
-        assert_eq!(s.to_case(Case::Snake), conv.convert(s));
+    /// Converts the `Converter` into an [`OwnedCase`] with the same boundaries, pattern, and
+    /// delimiter.  Other options, like a joiner or acronyms, are not part of the case.
+    /// ```
+    /// use convert_case::{Boundary, Converter, Pattern};
+    ///
+    /// let case = Converter::new()
+    ///     .set_boundaries(&[Boundary::Space])
+    ///     .set_pattern(Pattern::Uppercase)
+    ///     .set_delim("+")
+    ///     .into_case();
+    /// assert_eq!(vec![Boundary::Space], case.boundaries);
+    /// assert_eq!("A+B", case.converter().convert("a b"));
+    /// ```
+    pub fn into_case(self) -> OwnedCase {
+        OwnedCase {
+            boundaries: self.boundaries,
+            pattern: self.pattern,
+            delim: self.delim,
+        }
     }
 
-    #[test]
-    fn custom_pattern() {
-        let conv = Converter::new()
-            .to_case(Case::Snake)
-            .set_pattern(Pattern::Sentence);
-        assert_eq!("Bjarne_case", conv.convert("bjarne case"));
+    /// Sets the locale whose case mappings are used by the pattern.  The default locale uses
+    /// the Unicode default mappings, so this only matters for languages like Turkish.
+    /// ```
+    /// use convert_case::{Case, Converter, Locale};
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Snake)
+    ///     .set_locale(Locale::Turkish);
+    /// assert_eq!("ılık_içecek", conv.convert("ILIK İÇECEK"));
+    /// ```
+    pub fn set_locale(mut self, locale: Locale) -> Self {
+        self.mapping.locale = locale;
+        self
     }
 
-    #[test]
-    fn custom_delim() {
-        let conv = Converter::new().set_delim("..");
-        assert_eq!("oh..My", conv.convert("ohMy"));
+    /// Uppercases `ß` to the capital sharp s `ẞ`, instead of the Unicode default `SS`.  This
+    /// keeps `ß` when the result is converted back to lowercase, since `SS` lowercases to
+    /// `ss`.
+    /// ```
+    /// use convert_case::{Case, Casing, Converter};
+    ///
+    /// assert_eq!("STRASSE", "straße".to_case(Case::Upper));
+    /// assert_eq!("strasse", "STRASSE".to_case(Case::Lower));
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Upper)
+    ///     .capital_sharp_s();
+    /// assert_eq!("STRAẞE", conv.convert("straße"));
+    /// assert_eq!("straße", "STRAẞE".to_case(Case::Lower));
+    /// ```
+    pub fn capital_sharp_s(mut self) -> Self {
+        self.mapping.capital_sharp_s = true;
+        self
     }
 
-    #[test]
-    fn no_pattern() {
-        let conv = Converter::new()
-            .from_case(Case::Title)
+    /// Seeds the random number generator used by the `Random` and `PseudoRandom` patterns,
+    /// so converting the same string gives the same result every time.  Without a seed, a
+    /// thread-local generator is used.  Only available in the "random" feature.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Random)
+    ///     .set_seed(42);
+    /// assert_eq!(conv.convert("hello world"), conv.convert("hello world"));
+    /// ```
+    #[cfg(feature = "random")]
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets a function that mutates the words, used instead of the pattern.  Unlike a
+    /// [`Pattern`], the closure can capture state, like a lookup table of words.  The pattern
+    /// is ignored while a closure is set.  The closure is shared between clones of the
+    /// `Converter`.  With [`preserve_quoted`](Converter::preserve_quoted), quoted words aren't
+    /// passed to the closure, and are put back at their index in the words it returns.
+    /// [`preserve_acronyms`](Converter::preserve_acronyms) only applies when the closure
+    /// returns as many words as it is given.
+    /// ```
+    /// use std::collections::HashSet;
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let names: HashSet<&str> = ["rust", "mozilla"].into_iter().collect();
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Kebab)
+    ///     .set_pattern_closure(move |words| {
+    ///         words
+    ///             .iter()
+    ///             .map(|word| {
+    ///                 let lower = word.to_lowercase();
+    ///                 if names.contains(lower.as_str()) {
+    ///                     format!("{}{}", &lower[..1].to_uppercase(), &lower[1..])
+    ///                 } else {
+    ///                     lower
+    ///                 }
+    ///             })
+    ///             .collect()
+    ///     });
+    /// assert_eq!("learn-Rust-at-Mozilla", conv.convert("LEARN_RUST_AT_MOZILLA"));
+    /// ```
+    pub fn set_pattern_closure<F>(mut self, pattern_fn: F) -> Self
+    where
+        F: Fn(&[&str]) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.pattern_fn = Some(Arc::new(pattern_fn));
+        self
+    }
+
+    /// Sets a function that joins the mutated words into the final string, used instead of
+    /// joining with the delimiter.  This gives full control over the output, such as only
+    /// inserting separators between some words.  The delimiter is ignored while a joiner is set.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Lower)
+    ///     .set_joiner(|words| match words {
+    ///         [init @ .., last] if !init.is_empty() => {
+    ///             format!("{} and {}", init.join(", "), last)
+    ///         }
+    ///         _ => words.concat(),
+    ///     });
+    /// assert_eq!("bread, milk and eggs", conv.convert("Bread Milk Eggs"));
+    /// ```
+    pub fn set_joiner<F>(mut self, joiner: F) -> Self
+    where
+        F: Fn(&[String]) -> String + Send + Sync + 'static,
+    {
+        self.joiner = Some(Arc::new(joiner));
+        self
+    }
+
+    /// Treats any span enclosed in double quotes `"` as a single, opaque word.  The span is not
+    /// split on boundaries and its characters are not mutated by the pattern, so it appears
+    /// in the output exactly as it did in the input, quotes included.  An unmatched quote is
+    /// treated like any other character.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Snake)
+    ///     .preserve_quoted();
+    /// assert_eq!(
+    ///     r#"prefix_"LeaveMeAlone"_suffix"#,
+    ///     conv.convert(r#"Prefix "LeaveMeAlone" Suffix"#)
+    /// );
+    /// ```
+    pub fn preserve_quoted(mut self) -> Self {
+        self.preserve_quoted = true;
+        self
+    }
+
+    /// Treats numeric literals as single words, so they aren't split apart by boundaries.  A
+    /// numeric literal is a run of digits with an optional decimal part, like `3.14`, and an
+    /// optional sign, like `-5`.  Digits may be grouped in threes with commas, like `10,000`.
+    /// A `-` or `+` directly after a digit is not a sign.
+    /// ```
+    /// use convert_case::{Boundary, Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .add_boundary(Boundary::from_delim("."))
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("pi_3_14_val", conv.convert("pi3.14val"));
+    ///
+    /// let conv = conv.keep_numbers();
+    /// assert_eq!("pi_3.14_val", conv.convert("pi3.14val"));
+    /// assert_eq!("temp_-5_deg", conv.convert("temp-5deg"));
+    /// ```
+    pub fn keep_numbers(mut self) -> Self {
+        self.keep_numbers = true;
+        self
+    }
+
+    /// Keeps numbers whole even where `_`, `,` or `.` would otherwise be boundaries.  This
+    /// works like [`keep_numbers`](Converter::keep_numbers), and also accepts digits grouped
+    /// with underscores, like `1_000`.  Unlike `keep_numbers`, a `-` or `+` before a number
+    /// isn't taken as a sign, so hyphens still split words.  Passing `false` turns the option
+    /// off again, but doesn't undo `keep_numbers`.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Kebab);
+    /// assert_eq!("value-1-000", conv.convert("value1_000"));
+    ///
+    /// let conv = conv.number_aware(true);
+    /// assert_eq!("value-1_000", conv.convert("value1_000"));
+    /// assert_eq!("total-1,000-items", conv.convert("total1,000Items"));
+    /// ```
+    pub fn number_aware(mut self, enabled: bool) -> Self {
+        self.number_aware = enabled;
+        self
+    }
+
+    /// Removes any character that isn't a letter or digit from each word before the pattern is
+    /// applied.  Words left empty are dropped.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// assert_eq!("10,000_days", conv.convert("10,000Days"));
+    ///
+    /// let conv = conv.strip_symbols();
+    /// assert_eq!("10000_days", conv.convert("10,000Days"));
+    /// assert_eq!("dont_stop", conv.convert("Don't Stop!"));
+    /// ```
+    pub fn strip_symbols(mut self) -> Self {
+        self.strip_symbols = true;
+        self
+    }
+
+    /// Sets the converter up for making slugs: characters that aren't letters or digits are
+    /// removed, words are lowercased, and the words left are joined with `delim`.  Words made
+    /// only of symbols are dropped, so no empty words end up between delimiters.  This is the
+    /// same as [`strip_symbols`](Converter::strip_symbols) with the lowercase pattern and
+    /// `delim` as the delimiter, and the boundaries are kept as they are.
+    /// ```
+    /// use convert_case::Converter;
+    ///
+    /// let conv = Converter::new().slugify("-");
+    /// assert_eq!("hello-world-test", conv.convert("Hello, World!  Test"));
+    /// assert_eq!("caffè-crème-2", conv.convert("Caffè Crème #2"));
+    /// ```
+    pub fn slugify(self, delim: &str) -> Self {
+        self.set_pattern(Pattern::Lowercase)
+            .set_delim(delim)
+            .strip_symbols()
+    }
+
+    /// Splits words made only of uppercase letters into single letters, so `"ABC"` becomes
+    /// the three words `"A"`, `"B"` and `"C"`.  Without this, an all caps word is kept whole,
+    /// since the acronym boundary only splits before an uppercase letter followed by a
+    /// lowercase one.
+    ///
+    /// A run of uppercase letters that is followed by a lowercase letter, like the `"ABC"` in
+    /// `"ABCd"`, is left to the acronym boundary, which splits it into `"AB"` and `"Cd"`.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// assert_eq!("abc", conv.convert("ABC"));
+    ///
+    /// let conv = conv.split_all_caps();
+    /// assert_eq!("a_b_c", conv.convert("ABC"));
+    /// assert_eq!("ab_cd", conv.convert("ABCd"));
+    /// assert_eq!("get_u_r_l", conv.convert("getURL"));
+    /// ```
+    pub fn split_all_caps(mut self) -> Self {
+        self.split_all_caps = true;
+        self
+    }
+
+    /// Capitalizes the first letter of each sentence after the pattern is applied.  A
+    /// sentence starts at the first word and at each word after a word ending with a period,
+    /// so this is meant for space delimited text with periods kept in the words.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Lower)
+    ///     .sentence_per_period();
+    /// assert_eq!("Hello world. Foo bar", conv.convert("hello world. foo bar"));
+    /// ```
+    pub fn sentence_per_period(mut self) -> Self {
+        self.sentence_per_period = true;
+        self
+    }
+
+    /// Keeps a number at the start of the string attached to the word that follows it, so
+    /// `"3dModel"` is split into `"3d"` and `"Model"` instead of `"3"`, `"d"` and `"Model"`.
+    /// The number is only joined when no delimiter separates it from the next word.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().from_case(Case::Camel).to_case(Case::Pascal);
+    /// assert_eq!("3DModel", conv.convert("3dModel"));
+    ///
+    /// let conv = conv.keep_leading_number();
+    /// assert_eq!("3dModel", conv.convert("3dModel"));
+    /// ```
+    pub fn keep_leading_number(mut self) -> Self {
+        self.keep_leading_number = true;
+        self
+    }
+
+    /// Returns the original string instead of an empty one when nothing is left after
+    /// conversion, like when the string is only delimiters.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// assert_eq!("", conv.convert("___"));
+    ///
+    /// let conv = conv.fallback_to_input();
+    /// assert_eq!("___", conv.convert("___"));
+    /// assert_eq!("my_var", conv.convert("myVar"));
+    /// ```
+    pub fn fallback_to_input(mut self) -> Self {
+        self.fallback_to_input = true;
+        self
+    }
+
+    /// Wraps the converted string in double quotes when it can't be used as a plain SQL
+    /// identifier: when it is a reserved word, like `select` or `order`, or when it has
+    /// characters other than ASCII letters, digits and underscores, or starts with a digit.
+    /// Double quotes inside the string are escaped by doubling them.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake).sql_identifier();
+    /// assert_eq!("user_id", conv.convert("userId"));
+    /// assert_eq!("\"order\"", conv.convert("Order"));
+    /// assert_eq!("\"$amount\"", conv.convert("$amount"));
+    /// ```
+    pub fn sql_identifier(mut self) -> Self {
+        self.sql_identifier = true;
+        self
+    }
+
+    /// Keeps the whitespace at the start and end of the string exactly as it is, instead of
+    /// treating it as delimiters.  Only the text between is converted.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Snake);
+    /// assert_eq!("foo_bar", conv.convert("  fooBar "));
+    ///
+    /// let conv = conv.preserve_edge_whitespace();
+    /// assert_eq!("  foo_bar ", conv.convert("  fooBar "));
+    /// ```
+    pub fn preserve_edge_whitespace(mut self) -> Self {
+        self.preserve_edge_whitespace = true;
+        self
+    }
+
+    /// Keeps a single delimiter from the start and the end of the string, like the
+    /// underscore in `_private` or the ones around `__init__`, instead of removing them with
+    /// the other delimiters.  The delimiter is kept as it appears in the input, and only
+    /// delimiters that the converter splits on are kept.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().from_case(Case::Snake).to_case(Case::Snake);
+    /// assert_eq!("private", conv.convert("_private"));
+    ///
+    /// let conv = conv.preserve_affixes(true);
+    /// assert_eq!("_private", conv.convert("_private"));
+    /// assert_eq!("_init_", conv.convert("__init__"));
+    /// ```
+    pub fn preserve_affixes(mut self, preserve: bool) -> Self {
+        self.preserve_affixes = preserve;
+        self
+    }
+
+    /// Uppercases any letter that directly follows a digit, after the pattern has been
+    /// applied.  This keeps the letter after a digit capitalized even when the digit
+    /// boundaries are not used to split words.
+    /// ```
+    /// use convert_case::{Boundary, Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .from_case(Case::Camel)
+    ///     .remove_boundary(Boundary::DigitLower)
+    ///     .to_case(Case::Pascal);
+    /// assert_eq!("User2name", conv.convert("user2name"));
+    ///
+    /// let conv = conv.capitalize_after_digits();
+    /// assert_eq!("User2Name", conv.convert("user2name"));
+    /// ```
+    pub fn capitalize_after_digits(mut self) -> Self {
+        self.capitalize_after_digits = true;
+        self
+    }
+
+    /// Sets prefixes, like the `E` in `EColor` or the `T` in `TWidget`, that are removed from
+    /// the start of the string before it is split.  A prefix is only removed when it is
+    /// followed by a capitalized word, so `"TVShow"` and `"Example"` are left alone.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .strip_type_prefixes(&["E", "T", "I"])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("color_red", conv.convert("EColorRed"));
+    /// assert_eq!("widget", conv.convert("TWidget"));
+    /// assert_eq!("tv_show", conv.convert("TVShow"));
+    /// ```
+    pub fn strip_type_prefixes(mut self, prefixes: &[&str]) -> Self {
+        self.type_prefixes = prefixes.iter().map(|p| p.to_string()).collect();
+        self
+    }
+
+    /// Removes the `k` from the start of constants named in the style of `kDefaultValue`.  The
+    /// `k` is only removed when it is lowercase and followed by an uppercase letter.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().strip_k_prefix().to_case(Case::Snake);
+    /// assert_eq!("default_value", conv.convert("kDefaultValue"));
+    /// assert_eq!("kernel_size", conv.convert("kernelSize"));
+    /// ```
+    pub fn strip_k_prefix(mut self) -> Self {
+        self.strip_k_prefix = true;
+        self
+    }
+
+    /// Sets the shortest run of uppercase letters that the [`Acronym`](Boundary::Acronym)
+    /// boundary splits.  The run includes the first letter of the next word, so
+    /// `"IOStream"` has a run of three letters, `"IOS"`.  Shorter runs are kept as one word,
+    /// unless the letters before the split are one of the acronyms set with
+    /// [`with_acronyms`](Converter::with_acronyms).
+    ///
+    /// The acronym boundary always needs a run of at least two letters, so lengths up to 2
+    /// behave like the default.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Snake)
+    ///     .set_acronym_min_len(4);
+    /// assert_eq!("iostream", conv.convert("IOStream"));
+    /// assert_eq!("http_request", conv.convert("HTTPRequest"));
+    ///
+    /// let conv = conv.with_acronyms(&["IO"]);
+    /// assert_eq!("io_stream", conv.convert("IOStream"));
+    /// ```
+    pub fn set_acronym_min_len(mut self, min_len: usize) -> Self {
+        self.acronym_min_len = min_len;
+        self
+    }
+
+    /// Sets a list of acronyms whose spelling is kept whenever the pattern would capitalize
+    /// them.  A word that matches an acronym, ignoring case, is replaced by the acronym as it
+    /// is written in the list.  Words the pattern doesn't capitalize, like the first word in
+    /// camel case, are left alone.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Pascal)
+    ///     .with_acronyms(&["IO", "XML", "HTTP"]);
+    /// assert_eq!("IOStream", conv.convert("io_stream"));
+    /// assert_eq!("HTTPRequest", conv.convert("http_request"));
+    ///
+    /// let conv = conv.to_case(Case::Camel);
+    /// assert_eq!("xmlHTTPRequest", conv.convert("xml_http_request"));
+    /// ```
+    pub fn with_acronyms(mut self, acronyms: &[&str]) -> Self {
+        self.acronyms = acronyms.iter().map(|a| a.to_string()).collect();
+        self
+    }
+
+    /// Keeps words that are all uppercase in the input, like `XML` or `HTTP`, in uppercase
+    /// wherever the pattern would capitalize them.  Unlike
+    /// [`with_acronyms`](Converter::with_acronyms), any word of two or more uppercase letters
+    /// is kept, so this suits inputs in upper snake case where acronyms can't be told apart
+    /// from other words.  Words the pattern doesn't capitalize, like the first word in camel
+    /// case, are changed as usual.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().from_case(Case::UpperSnake).to_case(Case::Camel);
+    /// assert_eq!("xmlHttp", conv.convert("XML_HTTP"));
+    ///
+    /// let conv = conv.preserve_acronyms();
+    /// assert_eq!("xmlHTTP", conv.convert("XML_HTTP"));
+    /// ```
+    pub fn preserve_acronyms(mut self) -> Self {
+        self.preserve_acronyms = true;
+        self
+    }
+
+    /// Keeps only the first `max_words` words, followed by the words `and N more` where `N`
+    /// is the number of words dropped.  The added words are mutated and joined like the
+    /// others.  Strings with `max_words` words or fewer are converted as usual.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Pascal).summarize(2);
+    /// assert_eq!("GetUserAnd2More", conv.convert("get_user_by_id"));
+    /// assert_eq!("GetUser", conv.convert("get_user"));
+    /// ```
+    pub fn summarize(mut self, max_words: usize) -> Self {
+        self.max_words = Some(max_words);
+        self
+    }
+
+    /// Sets words that are always lowercased and words that are always uppercased, no matter
+    /// what the pattern does to them.  Words are matched ignoring case.  This is useful with
+    /// title case for conjunctions and abbreviations.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .title_with(&["and", "of", "the"], &["usa"])
+    ///     .to_case(Case::Title);
+    /// assert_eq!("War and Peace", conv.convert("war and peace"));
+    /// assert_eq!("History of the USA", conv.convert("history_of_the_usa"));
+    /// ```
+    pub fn title_with(mut self, lower: &[&str], upper: &[&str]) -> Self {
+        self.lower_words = lower.iter().map(|w| w.to_lowercase()).collect();
+        self.upper_words = upper.iter().map(|w| w.to_lowercase()).collect();
+        self
+    }
+
+    /// When the pattern is [`Pattern::Camel`], only lowercases the first letter of the first
+    /// word instead of the whole word.  This keeps a leading acronym mostly uppercase.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new().to_case(Case::Camel);
+    /// assert_eq!("httpServer", conv.convert("HTTPServer"));
+    ///
+    /// let conv = conv.camel_lower_first_letter_only();
+    /// assert_eq!("hTTPServer", conv.convert("HTTPServer"));
+    /// ```
+    pub fn camel_lower_first_letter_only(mut self) -> Self {
+        self.camel_lower_first_letter_only = true;
+        self
+    }
+
+    /// Removes diacritics from letters, like `é` to `e`, by decomposing each character and
+    /// dropping the combining marks.  This is useful for producing ASCII-friendly slugs.
+    /// Characters without a decomposition, like `ß` or `ø`, are left unchanged.  Only
+    /// available in the "diacritics" feature.
+    /// ```
+    /// use convert_case::{Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .to_case(Case::Kebab)
+    ///     .strip_diacritics();
+    /// assert_eq!("cafe-munchen", conv.convert("café_münchen"));
+    /// ```
+    #[cfg(feature = "diacritics")]
+    pub fn strip_diacritics(mut self) -> Self {
+        self.strip_diacritics = true;
+        self
+    }
+}
+
+/// Common SQL reserved words, lowercase and sorted.
+#[rustfmt::skip]
+const SQL_RESERVED_WORDS: &[&str] = &[
+    "all", "alter", "and", "any", "as", "asc", "between", "by", "case", "check", "column",
+    "constraint", "create", "cross", "default", "delete", "desc", "distinct", "drop", "else",
+    "end", "exists", "foreign", "from", "full", "grant", "group", "having", "in", "index",
+    "inner", "insert", "into", "is", "join", "key", "left", "like", "limit", "not", "null",
+    "offset", "on", "or", "order", "outer", "primary", "references", "right", "select", "set",
+    "table", "then", "to", "union", "unique", "update", "user", "using", "values", "view",
+    "when", "where", "with",
+];
+
+/// Whether `ident` has to be quoted to be used as a SQL identifier.  An empty string is left
+/// alone.
+fn needs_sql_quotes(ident: &str) -> bool {
+    let Some(first) = ident.chars().next() else {
+        return false;
+    };
+    let plain = (first.is_ascii_alphabetic() || first == '_')
+        && ident.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    !plain
+        || SQL_RESERVED_WORDS
+            .binary_search(&ident.to_ascii_lowercase().as_str())
+            .is_ok()
+}
+
+fn quote_sql_identifier(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
+
+/// Joins the first word to the second if the first is a number at the start of `s` and
+/// nothing was consumed between them.
+fn join_leading_number<'s>(s: &'s str, words: &mut Vec<&'s str>) {
+    let [first, second, ..] = words[..] else {
+        return;
+    };
+    let offset = |word: &str| word.as_ptr() as usize - s.as_ptr() as usize;
+    let is_number = first.chars().all(|c| c.is_ascii_digit());
+    if is_number && offset(first) == 0 && offset(second) == first.len() {
+        words.splice(0..2, [&s[..first.len() + second.len()]]);
+    }
+}
+
+/// Whether `word` has more than one grapheme and every grapheme is uppercase.
+fn is_all_caps(word: &str) -> bool {
+    word.graphemes(true).nth(1).is_some()
+        && word
+            .graphemes(true)
+            .all(|g| g.chars().next().is_some_and(char::is_uppercase))
+}
+
+/// Whether `s` is a run of uppercase letters followed by a lowercase letter, meaning a word
+/// that ends just before `s` belongs to an acronym that runs into the next word.
+fn starts_with_lowercase_run(s: &str) -> bool {
+    s.chars()
+        .find(|c| !c.is_uppercase())
+        .is_some_and(char::is_lowercase)
+}
+
+/// Finds the first numeric literal at or after `from`, with an optional decimal part, returning
+/// its byte range.  If `signs` is set, a `-` or `+` before the number is part of it.  If
+/// `underscores` is set, runs of digits may also be separated by single underscores.
+fn number_span(s: &str, from: usize, signs: bool, underscores: bool) -> Option<(usize, usize)> {
+    let bytes = s.as_bytes();
+    let is_digit_at = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    let digits_from = |i: usize| {
+        let run_end = |i: usize| i + bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let mut end = run_end(i);
+        while underscores && bytes.get(end) == Some(&b'_') && is_digit_at(end + 1) {
+            end = run_end(end + 1);
+        }
+        end
+    };
+
+    let start = (from..bytes.len()).find(|&i| {
+        is_digit_at(i)
+            || (signs
+                && matches!(bytes[i], b'-' | b'+')
+                && is_digit_at(i + 1)
+                && !(i > 0 && is_digit_at(i - 1)))
+    })?;
+    let mut end = digits_from(if is_digit_at(start) { start } else { start + 1 });
+    while bytes.get(end) == Some(&b',') && digits_from(end + 1) == end + 4 {
+        end += 4;
+    }
+    if bytes.get(end) == Some(&b'.') && is_digit_at(end + 1) {
+        end = digits_from(end + 1);
+    }
+    Some((start, end))
+}
+
+/// Finds the first span enclosed in double quotes, returning the byte range including the
+/// quotes themselves.
+fn quoted_span(s: &str) -> Option<(usize, usize)> {
+    let start = s.find('"')?;
+    let end = start + 1 + s[start + 1..].find('"')? + 1;
+    Some((start, end))
+}
+
+fn is_quoted(word: &str) -> bool {
+    word.len() >= 2 && word.starts_with('"') && word.ends_with('"')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Casing;
+    use crate::Pattern;
+
+    #[test]
+    fn snake_converter_from_case() {
+        let conv = Converter::new().to_case(Case::Snake);
+        let s = String::from("my var name");
+        assert_eq!(s.to_case(Case::Snake), conv.convert(s));
+    }
+
+    #[test]
+    fn snake_converter_from_scratch() {
+        let conv = Converter::new()
+            .set_delim("_")
+            .set_pattern(Pattern::Lowercase);
+        let s = String::from("my var name");
+        assert_eq!(s.to_case(Case::Snake), conv.convert(s));
+    }
+
+    #[test]
+    fn custom_pattern() {
+        let conv = Converter::new()
+            .to_case(Case::Snake)
+            .set_pattern(Pattern::Sentence);
+        assert_eq!("Bjarne_case", conv.convert("bjarne case"));
+    }
+
+    #[test]
+    fn custom_delim() {
+        let conv = Converter::new().set_delim("..");
+        assert_eq!("oh..My", conv.convert("ohMy"));
+    }
+
+    #[test]
+    fn no_pattern() {
+        let conv = Converter::new()
+            .from_case(Case::Title)
             .to_case(Case::Kebab)
             .remove_pattern();
         assert_eq!("wIErd-CASing", conv.convert("wIErd CASing"));
     }
 
     #[test]
-    fn no_delim() {
+    fn preserve_case_matches_no_pattern() {
+        let no_pattern = Converter::new()
+            .from_case(Case::Title)
+            .to_case(Case::Kebab)
+            .remove_pattern();
+        let preserve = Converter::preserve_case(Case::Kebab);
+        assert_eq!("wIErd-CASing", preserve.convert("wIErd CASing"));
+        assert_eq!(
+            no_pattern.convert("wIErd CASing"),
+            preserve.convert("wIErd CASing")
+        );
+        assert_eq!(None, preserve.pattern);
+        assert_eq!(
+            "a_B_c",
+            Converter::preserve_case(Case::Snake).convert("a-B c")
+        );
+    }
+
+    #[test]
+    fn no_delim() {
+        let conv = Converter::new()
+            .from_case(Case::Title)
+            .to_case(Case::Kebab)
+            .remove_delim();
+        assert_eq!("justflat", conv.convert("Just Flat"));
+    }
+
+    #[test]
+    fn no_digit_boundaries() {
+        let conv = Converter::new()
+            .remove_boundaries(&Boundary::digits())
+            .to_case(Case::Snake);
+        assert_eq!("test_08bound", conv.convert("Test 08Bound"));
+        assert_eq!("a8a_a8a", conv.convert("a8aA8A"));
+    }
+
+    #[test]
+    fn remove_boundary() {
+        let conv = Converter::new()
+            .remove_boundary(Boundary::DigitUpper)
+            .to_case(Case::Snake);
+        assert_eq!("test_08bound", conv.convert("Test 08Bound"));
+        assert_eq!("a_8_a_a_8a", conv.convert("a8aA8A"));
+    }
+
+    #[test]
+    fn remove_custom_delim_boundary() {
+        let conv = Converter::new()
+            .add_boundary(Boundary::from_delim("::"))
+            .to_case(Case::Snake);
+        assert_eq!("std_fmt", conv.convert("std::fmt"));
+
+        let conv = conv.remove_boundary(Boundary::from_delim("::"));
+        assert_eq!(Boundary::defaults().to_vec(), conv.boundaries);
+        assert_eq!("std::fmt", conv.convert("std::fmt"));
+    }
+
+    #[test]
+    fn add_boundary() {
+        let conv = Converter::new()
+            .from_case(Case::Snake)
+            .to_case(Case::Kebab)
+            .add_boundary(Boundary::LowerUpper);
+        assert_eq!("word-word-word", conv.convert("word_wordWord"));
+    }
+
+    #[test]
+    fn add_boundaries() {
+        let conv = Converter::new()
+            .from_case(Case::Snake)
+            .to_case(Case::Kebab)
+            .add_boundaries(&[Boundary::LowerUpper, Boundary::UpperLower]);
+        assert_eq!("word-word-w-ord", conv.convert("word_wordWord"));
+    }
+
+    #[test]
+    fn reuse_after_change() {
+        let conv = Converter::new().from_case(Case::Snake).to_case(Case::Kebab);
+        assert_eq!("word-wordword", conv.convert("word_wordWord"));
+
+        let conv = conv.add_boundary(Boundary::LowerUpper);
+        assert_eq!("word-word-word", conv.convert("word_wordWord"));
+    }
+
+    fn and_list(words: &[String]) -> String {
+        match words {
+            [] => String::new(),
+            [word] => word.clone(),
+            [init @ .., last] => format!("{} and {}", init.join(", "), last),
+        }
+    }
+
+    #[test]
+    fn custom_joiner() {
+        let conv = Converter::new().to_case(Case::Lower).set_joiner(and_list);
+        assert_eq!("a, b and c", conv.convert("a b c"));
+        assert_eq!("a and b", conv.convert("a_b"));
+        assert_eq!("a", conv.convert("A"));
+        assert_eq!("", conv.convert(""));
+    }
+
+    #[test]
+    fn pattern_closure_captures_state() {
+        use std::collections::HashSet;
+
+        let acronyms: HashSet<String> = ["xml", "http"].map(String::from).into();
+        let conv = Converter::new()
+            .to_case(Case::Snake)
+            .set_pattern_closure(move |words| {
+                words
+                    .iter()
+                    .map(|word| {
+                        let lower = word.to_lowercase();
+                        if acronyms.contains(&lower) {
+                            word.to_uppercase()
+                        } else {
+                            lower
+                        }
+                    })
+                    .collect()
+            });
+        assert_eq!("XML_HTTP_request", conv.convert("XmlHttpRequest"));
+        let pascal = conv.clone().to_case(Case::Pascal).set_delim("_");
+        assert_eq!("XML_HTTP_request", pascal.convert("xml http request"));
+        assert_eq!("", conv.convert(""));
+    }
+
+    #[test]
+    fn convert_annotated_reports_source_case() {
+        let conv = Converter::new().to_case(Case::Snake);
+        let (converted, from) = conv.convert_annotated("getUserName", None);
+        assert_eq!("get_user_name", converted);
+        assert_eq!(Some(Case::Camel), from);
+
+        assert_eq!(
+            ("my_var".to_string(), Some(Case::Kebab)),
+            conv.convert_annotated("my-var", None)
+        );
+        assert_eq!(
+            ("my-var".to_string(), Some(Case::Title)),
+            conv.convert_annotated("my-var", Some(Case::Title))
+        );
+        assert_eq!(
+            ("my_var_name".to_string(), None),
+            conv.convert_annotated("my Var_name", None)
+        );
+    }
+
+    #[test]
+    fn preview_pairs_words() {
+        let owned = |words: &[(&str, &str)]| -> Vec<(String, String)> {
+            words
+                .iter()
+                .map(|&(a, b)| (a.to_string(), b.to_string()))
+                .collect()
+        };
+        let conv = Converter::new().to_case(Case::Snake);
+        assert_eq!(
+            owned(&[("get", "get"), ("HTTP", "http"), ("Response", "response")]),
+            conv.preview("getHTTPResponse")
+        );
+        let conv = conv.with_acronyms(&["HTTP"]).to_case(Case::Pascal);
+        assert_eq!(
+            owned(&[("get", "Get"), ("HTTP", "HTTP"), ("Response", "Response")]),
+            conv.preview("getHTTPResponse")
+        );
+        assert!(conv.preview("").is_empty());
+    }
+
+    #[test]
+    fn preview_pairs_each_word_with_its_own_mutation() {
+        let owned = |words: &[(&str, &str)]| -> Vec<(String, String)> {
+            words
+                .iter()
+                .map(|&(a, b)| (a.to_string(), b.to_string()))
+                .collect()
+        };
+        let conv = Converter::new().to_case(Case::Camel).strip_symbols();
+        assert_eq!(
+            owned(&[("a", "a"), ("!!", ""), ("b", "B")]),
+            conv.preview("a !! b")
+        );
+        assert_eq!("aB", conv.convert("a !! b"));
+
+        let conv = Converter::new().to_case(Case::Snake).summarize(1);
+        assert_eq!(
+            owned(&[("a", "a"), ("b", "b"), ("c", "c")]),
+            conv.preview("a b c")
+        );
+
+        let drop_first = |words: &[&str]| words.iter().skip(1).map(|w| w.to_string()).collect();
+        let conv = Converter::new().set_pattern_closure(drop_first);
+        assert_eq!(owned(&[("a", "b"), ("b", "")]), conv.preview("a b"));
+    }
+
+    #[test]
+    fn pattern_closure_keeps_quoted_words() {
+        let drop_first = |words: &[&str]| words.iter().skip(1).map(|w| w.to_lowercase()).collect();
+        let conv = Converter::new()
+            .set_delim("_")
+            .preserve_quoted()
+            .set_pattern_closure(drop_first);
+        assert_eq!(r#"c_"B""#, conv.convert(r#"a "B" c"#));
+        assert_eq!(r#"b_"C""#, conv.convert(r#"a b "C""#));
+        assert_eq!(r#""A"_c"#, conv.convert(r#""A" b c"#));
+
+        let lower = |words: &[&str]| words.iter().map(|w| w.to_lowercase()).collect();
+        let conv = conv.set_pattern_closure(lower);
+        assert_eq!(r#"a_"B"_c"#, conv.convert(r#"A "B" C"#));
+    }
+
+    #[test]
+    fn pattern_closure_with_preserve_acronyms() {
+        let drop_first = |words: &[&str]| Pattern::Capital.mutate(words.get(1..).unwrap_or(&[]));
+        let conv = Converter::new()
+            .set_delim("_")
+            .preserve_acronyms()
+            .set_pattern_closure(drop_first);
+        assert_eq!("Xml_C", conv.convert("a XML c"));
+
+        let title = |words: &[&str]| Pattern::Capital.mutate(words);
+        let conv = conv.set_pattern_closure(title);
+        assert_eq!("A_XML_C", conv.convert("a XML c"));
+    }
+
+    #[test]
+    fn joiner_ignores_delim() {
+        let conv = Converter::new()
+            .to_case(Case::Snake)
+            .set_joiner(|words| words.join("+"))
+            .set_delim("-");
+        assert_eq!("my+var+name", conv.convert("myVarName"));
+    }
+
+    #[test]
+    fn convert_into_reuses_buffer() {
+        let conv = Converter::new().to_case(Case::Kebab);
+        let mut buf = String::with_capacity(64);
+        for s in ["myVarName", "HTTPRequest", "", "a", "Another Long Title 22"] {
+            conv.convert_into(s, &mut buf);
+            assert_eq!(conv.convert(s), buf);
+        }
+    }
+
+    #[test]
+    fn convert_into_with_joiner() {
+        let conv = Converter::new().set_joiner(and_list);
+        let mut buf = String::from("old contents");
+        conv.convert_into("apples bananas cherries", &mut buf);
+        assert_eq!(conv.convert("apples bananas cherries"), buf);
+    }
+
+    #[test]
+    fn convert_to_bytes_matches_convert() {
+        let convs = [
+            Converter::new().to_case(Case::Kebab),
+            Converter::new().to_case(Case::Title),
+            Converter::new().set_joiner(and_list),
+        ];
+        for conv in convs {
+            for s in ["myVarName", "XMLHttpRequest 2", "", "ÀçcentedWörds"] {
+                let mut out = Vec::new();
+                conv.convert_to_bytes(s, &mut out);
+                assert_eq!(conv.convert(s), String::from_utf8(out).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn split_all_caps() {
+        let conv = Converter::new().split_all_caps().to_case(Case::Snake);
+        assert_eq!("a_b_c", conv.convert("ABC"));
+        assert_eq!("ab_cd", conv.convert("ABCd"));
+        assert_eq!("xml_http_request", conv.convert("XMLHttpRequest"));
+        assert_eq!("m_y_v_a_r", conv.convert("MY_VAR"));
+        assert_eq!("a_b_c_def", conv.convert("ABC_def"));
+        assert_eq!("a_def", conv.convert("A_def"));
+    }
+
+    #[test]
+    fn capital_sharp_s() {
+        assert_eq!("STRASSE", "straße".to_case(Case::Upper));
+        assert_eq!("Straße", "STRAẞE".to_case(Case::Title));
+
+        let conv = Converter::new().to_case(Case::Upper).capital_sharp_s();
+        assert_eq!("STRAẞE", conv.convert("straße"));
+        assert_eq!("ẞ", conv.convert("ß"));
+        let conv = Converter::new().to_case(Case::Train).capital_sharp_s();
+        assert_eq!("Groß-Straße", conv.convert("groß straße"));
+        let conv = Converter::new().to_case(Case::UpperSnake).capital_sharp_s();
+        assert_eq!("GROẞ_STRAẞE", conv.convert("groß straße"));
+    }
+
+    #[test]
+    fn turkish_title_with_stop_words() {
+        let conv = Converter::new()
+            .set_pattern(Pattern::title_case(&["ile"]))
+            .set_delim(" ")
+            .set_locale(Locale::Turkish);
+        assert_eq!("Işık ile İstanbul", conv.convert("IŞIK İLE istanbul"));
+    }
+
+    #[test]
+    fn turkish_camel_lower_first_letter_only() {
+        let conv = Converter::new()
+            .to_case(Case::Camel)
+            .camel_lower_first_letter_only()
+            .set_locale(Locale::Turkish);
+        assert_eq!("ıŞIKİstanbul", conv.convert("IŞIK istanbul"));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn turkish_seeded() {
+        let conv = Converter::new()
+            .to_case(Case::Upper)
+            .set_locale(Locale::Turkish)
+            .set_seed(1);
+        assert_eq!("İSTANBUL", conv.convert("istanbul"));
+        let conv = conv.to_case(Case::Title).capital_sharp_s();
+        assert_eq!("Işık Straße", conv.convert("IŞIK straße"));
+        assert_eq!("STRAẞE", conv.to_case(Case::Upper).convert("straße"));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn capital_sharp_s_random_seeded() {
+        for case in [Case::Random, Case::PseudoRandom] {
+            let conv = Converter::new().to_case(case).capital_sharp_s().set_seed(3);
+            let random = conv.convert("ßßßßßßßß");
+            assert!(random.contains('ẞ'), "{}", random);
+            assert!(random.chars().all(|c| c == 'ß' || c == 'ẞ'), "{}", random);
+        }
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn turkish_random_seeded() {
+        for case in [Case::Random, Case::PseudoRandom] {
+            let conv = Converter::new()
+                .to_case(case)
+                .set_locale(Locale::Turkish)
+                .set_seed(3);
+            let random = conv.convert("iiiiiiii");
+            assert!(random.contains('İ'), "{}", random);
+            assert!(!random.contains('I'), "{}", random);
+            assert!(random.chars().all(|c| c == 'i' || c == 'İ'), "{}", random);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn convert_path_filename() {
+        let conv = Converter::new().to_case(Case::Title);
+        let path = Path::new("blue-oyster.cult.gdz");
+        assert_eq!(
+            PathBuf::from("Blue Oyster.cult.gdz"),
+            conv.convert_path_filename(path, false)
+        );
+        assert_eq!(
+            PathBuf::from("Blue Oyster.Cult.Gdz"),
+            conv.convert_path_filename(path, true)
+        );
+
+        let conv = Converter::new().to_case(Case::Snake);
+        assert_eq!(
+            PathBuf::from("/home/user/.my_config.TOML"),
+            conv.convert_path_filename(Path::new("/home/user/.myConfig.TOML"), false)
+        );
+        assert_eq!(
+            PathBuf::from("src/my_module"),
+            conv.convert_path_filename(Path::new("src/MyModule"), true)
+        );
+        assert_eq!(
+            PathBuf::from("/"),
+            conv.convert_path_filename(Path::new("/"), true)
+        );
+    }
+
+    #[test]
+    fn fallback_to_input() {
+        let conv = Converter::new().fallback_to_input().to_case(Case::Snake);
+        assert_eq!("___", conv.convert("___"));
+        assert_eq!("", conv.convert(""));
+        assert_eq!("- -", conv.convert("- -"));
+        assert_eq!("my_var_name", conv.convert("MyVarName"));
+        assert_eq!("___", conv.convert_cow("___"));
+
+        let mut out = b"x=".to_vec();
+        conv.convert_to_bytes("---", &mut out);
+        assert_eq!(b"x=---", out.as_slice());
+        assert_eq!("___", conv.convert_detailed("___").1);
+    }
+
+    #[test]
+    fn sql_identifier() {
+        let conv = Converter::new().to_case(Case::Snake).sql_identifier();
+        assert_eq!("\"select\"", conv.convert("select"));
+        assert_eq!("\"group\"", conv.convert("GROUP"));
+        assert_eq!("first_name", conv.convert("firstName"));
+        assert_eq!("select_count", conv.convert("selectCount"));
+        assert_eq!("\"café\"", conv.convert("Café"));
+        assert_eq!("\"1_st_place\"", conv.convert("1stPlace"));
+        assert_eq!("\"$amount\"", conv.convert("$amount"));
+        assert_eq!("", conv.convert(""));
+
+        let conv = Converter::new().set_delim("\"").sql_identifier();
+        assert_eq!("\"a\"\"b\"", conv.convert("a b"));
+
+        let mut out = b"id=".to_vec();
+        Converter::new()
+            .to_case(Case::Snake)
+            .sql_identifier()
+            .convert_to_bytes("Order", &mut out);
+        assert_eq!(b"id=\"order\"", out.as_slice());
+    }
+
+    #[test]
+    fn preserve_edge_whitespace() {
         let conv = Converter::new()
-            .from_case(Case::Title)
-            .to_case(Case::Kebab)
-            .remove_delim();
-        assert_eq!("justflat", conv.convert("Just Flat"));
+            .to_case(Case::Snake)
+            .preserve_edge_whitespace();
+        assert_eq!("  foo_bar  ", conv.convert("  fooBar  "));
+        assert_eq!("\n\tmy_var_name", conv.convert("\n\tMy Var Name"));
+        assert_eq!("foo_bar", conv.convert("fooBar"));
+        assert_eq!("   ", conv.convert("   "));
+        assert_eq!("", conv.convert(""));
+        assert_eq!(" foo_bar ", conv.convert_detailed(" foo-bar ").1);
+
+        let mut out = b"x=".to_vec();
+        conv.convert_to_bytes(" fooBar", &mut out);
+        assert_eq!(b"x= foo_bar", out.as_slice());
+
+        let conv = conv.fallback_to_input().sql_identifier();
+        assert_eq!(" __ ", conv.convert(" __ "));
+        assert_eq!(" \"select\" ", conv.convert(" Select "));
     }
 
     #[test]
-    fn no_digit_boundaries() {
+    fn preserve_affixes() {
         let conv = Converter::new()
-            .remove_boundaries(&Boundary::digits())
+            .from_case(Case::Snake)
+            .to_case(Case::Snake)
+            .preserve_affixes(true);
+        assert_eq!("_private", conv.convert("_private"));
+        assert_eq!("_my_var_", conv.convert("__my__var__"));
+        assert_eq!("my_var_", conv.convert("my_var_"));
+        assert_eq!("my_var", conv.convert("my_var"));
+        assert_eq!("_", conv.convert("_"));
+        assert_eq!("__", conv.convert("___"));
+        assert_eq!("", conv.convert(""));
+        assert_eq!("my_var", conv.preserve_affixes(false).convert("_my_var_"));
+
+        let conv = Converter::new().to_case(Case::Camel).preserve_affixes(true);
+        assert_eq!("_privateField", conv.convert("_private_field"));
+        assert_eq!("-myVar ", conv.convert("-my var "));
+        assert_eq!("\"-select\"", conv.sql_identifier().convert("-select"));
+
+        let conv = Converter::new()
+            .set_boundaries(&[Boundary::from_delim("::")])
+            .to_case(Case::Snake)
+            .preserve_affixes(true);
+        assert_eq!("::std_fmt", conv.convert("::std::fmt"));
+        assert_eq!(":std", conv.convert(":std"));
+
+        let mut out = Vec::new();
+        Converter::new()
+            .to_case(Case::Snake)
+            .preserve_affixes(true)
+            .convert_to_bytes("_fooBar", &mut out);
+        assert_eq!(b"_foo_bar", out.as_slice());
+    }
+
+    #[test]
+    fn preserve_acronyms() {
+        let conv = Converter::new()
+            .from_case(Case::UpperSnake)
+            .to_case(Case::Camel)
+            .preserve_acronyms();
+        assert_eq!("xmlHTTP", conv.convert("XML_HTTP"));
+        assert_eq!("helloWORLD", conv.convert("HELLO_WORLD"));
+        assert_eq!("aB", conv.convert("A_B"));
+
+        let conv = conv.to_case(Case::Pascal);
+        assert_eq!("XMLHTTPRequest", conv.convert("XML_HTTP_Request"));
+
+        let conv = conv.to_case(Case::Snake);
+        assert_eq!("xml_http", conv.convert("XML_HTTP"));
+
+        let conv = Converter::new().to_case(Case::Title).preserve_acronyms();
+        assert_eq!("Parse JSON Input", conv.convert("parse JSON input"));
+        assert_eq!("Über GRÖSSE", conv.convert("über GRÖSSE"));
+    }
+
+    #[test]
+    fn sql_reserved_words_sorted() {
+        assert!(SQL_RESERVED_WORDS.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn from_cases_matches_built_converter() {
+        let inputs = [
+            "myVarName",
+            "my-var_name",
+            "XMLHttp Request",
+            "v2_api-3D",
+            "",
+        ];
+        for case in Case::deterministic_cases() {
+            let explicit = Converter::new().to_case(case);
+            let from_case: Converter = case.into();
+            for s in inputs {
+                assert_eq!(explicit.convert(s), from_case.convert(s));
+            }
+        }
+
+        let explicit = Converter::new().from_case(Case::Kebab).to_case(Case::Snake);
+        let from_pair = Converter::from((Case::Kebab, Case::Snake));
+        for s in inputs {
+            assert_eq!(explicit.convert(s), from_pair.convert(s));
+        }
+        assert_eq!("my_varname", from_pair.convert("my-varName"));
+    }
+
+    #[test]
+    fn screaming_keeps_delim() {
+        let conv = Converter::new().to_case(Case::Dot).screaming();
+        assert_eq!("SERVER.HTTP.PORT", conv.convert("server http port"));
+        assert_eq!("SERVER.HTTP.PORT", conv.convert("serverHttpPort"));
+
+        let conv = Converter::new()
+            .from_case(Case::Dot)
+            .to_case(Case::Dot)
+            .screaming();
+        assert_eq!("SERVER.V2", conv.convert("server.v2"));
+
+        let conv = Converter::new().to_case(Case::Path).screaming();
+        assert_eq!("SRC/LIB", conv.convert("src lib"));
+        let snake = Converter::new().to_case(Case::Snake);
+        assert_eq!("MY_VAR", snake.screaming().convert("myVar"));
+    }
+
+    #[test]
+    fn join_words_skips_segmentation() {
+        let conv = Converter::new().to_case(Case::Camel);
+        assert_eq!(
+            "getTotalLength",
+            conv.join_words(&["get", "total", "length"])
+        );
+        assert_eq!("myVar-name", conv.join_words(&["my", "var-name"]));
+        assert_eq!("", conv.join_words(&[]));
+
+        let conv = Converter::new()
+            .to_case(Case::Snake)
+            .with_acronyms(&["HTTP"]);
+        assert_eq!("http_request", conv.join_words(&["HTTP", "Request"]));
+        let conv = conv.to_case(Case::Pascal);
+        assert_eq!("HTTPRequest", conv.join_words(&["http", "request"]));
+    }
+
+    #[test]
+    fn convert_in_place_keeps_capacity() {
+        let conv = Converter::new().to_case(Case::Snake);
+        let mut s = String::with_capacity(64);
+        for input in ["MyVarName", "XMLHttpRequest", "", "a"] {
+            s.clear();
+            s.push_str(input);
+            conv.convert_in_place(&mut s);
+            assert_eq!(conv.convert(input), s);
+            assert_eq!(64, s.capacity());
+        }
+
+        let conv = Converter::new().to_case(Case::Kebab).fallback_to_input();
+        let mut s = String::from("___");
+        conv.convert_in_place(&mut s);
+        assert_eq!("___", s);
+    }
+
+    #[test]
+    fn convert_all_matches_convert() {
+        let conv = Converter::new().to_case(Case::Snake);
+        let inputs = vec![
+            "myVarName",
+            "XMLHttpRequest",
+            "",
+            "Title Case 22",
+            "ÀçcentedWörds",
+        ];
+        let expected: Vec<String> = inputs.iter().map(|s| conv.convert(s)).collect();
+        assert_eq!(expected, conv.convert_all(&inputs));
+        assert_eq!(
+            expected,
+            conv.convert_all(inputs.iter().map(|s| s.to_string()))
+        );
+        assert!(conv.convert_all(Vec::<String>::new()).is_empty());
+    }
+
+    #[test]
+    fn convert_all_clears_scratch_between_strings() {
+        let conv = Converter::new().keep_leading_number().to_case(Case::Kebab);
+        assert_eq!(
+            vec!["one-two-three-four", "3d-model", "", "x"],
+            conv.convert_all(["oneTwoThreeFour", "3dModel", "", "X"])
+        );
+    }
+
+    #[test]
+    fn keep_leading_number() {
+        let pascal = Converter::new().keep_leading_number().to_case(Case::Pascal);
+        assert_eq!("3dModel", pascal.convert("3dModel"));
+        assert_eq!("2024report", pascal.convert("2024Report"));
+        assert_eq!("3dModel", pascal.convert("3D model"));
+        assert_eq!("3Model", pascal.convert("3 model"));
+        assert_eq!("Model3D", pascal.convert("model3d"));
+
+        let snake = Converter::new().keep_leading_number().to_case(Case::Snake);
+        assert_eq!("3d_model", snake.convert("3dModel"));
+        assert_eq!("3_model", snake.convert("3-model"));
+        assert_eq!("42", snake.convert("42"));
+        assert_eq!("", snake.convert(""));
+    }
+
+    #[test]
+    fn sentence_per_period() {
+        let conv = Converter::new().to_case(Case::Lower).sentence_per_period();
+        assert_eq!("Hello world. Foo bar", conv.convert("hello world. foo bar"));
+        assert_eq!("One. Two. Three", conv.convert("ONE. TWO. THREE"));
+        assert_eq!("End.", conv.convert("end."));
+        let conv = Converter::new()
+            .set_pattern(Pattern::Sentence)
+            .set_delim(" ")
+            .sentence_per_period();
+        assert_eq!("It works. Again", conv.convert("it WORKS. again"));
+    }
+
+    #[test]
+    fn strip_type_prefixes() {
+        let conv = Converter::new()
+            .strip_type_prefixes(&["E", "T", "I"])
             .to_case(Case::Snake);
-        assert_eq!("test_08bound", conv.convert("Test 08Bound"));
-        assert_eq!("a8a_a8a", conv.convert("a8aA8A"));
+        assert_eq!("color_red", conv.convert("EColorRed"));
+        assert_eq!("widget_base", conv.convert("TWidgetBase"));
+        assert_eq!("drawable", conv.convert("IDrawable"));
+        assert_eq!("example", conv.convert("Example"));
+        assert_eq!("io_stream", conv.convert("IOStream"));
+        assert_eq!("e", conv.convert("E"));
+        assert_eq!("color_e_red", conv.convert("ColorERed"));
+
+        let conv = Converter::new()
+            .strip_type_prefixes(&["m_"])
+            .to_case(Case::Camel);
+        assert_eq!("count", conv.convert("m_Count"));
+        assert_eq!("mCount", conv.convert("m_count"));
     }
 
     #[test]
-    fn remove_boundary() {
+    fn strip_k_prefix() {
+        let conv = Converter::new().strip_k_prefix().to_case(Case::Snake);
+        assert_eq!("default_value", conv.convert("kDefaultValue"));
+        assert_eq!("max", conv.convert("kMAX"));
+        assert_eq!("default_value", conv.convert("DefaultValue"));
+        assert_eq!("k_default", conv.convert("KDefault"));
+        assert_eq!("k", conv.convert("k"));
+        assert_eq!("kilo_byte", conv.convert("kiloByte"));
+
+        let conv = Converter::new().to_case(Case::Snake);
+        assert_eq!("k_default_value", conv.convert("kDefaultValue"));
+
         let conv = Converter::new()
-            .remove_boundary(Boundary::DigitUpper)
+            .strip_k_prefix()
+            .strip_type_prefixes(&["E"])
             .to_case(Case::Snake);
-        assert_eq!("test_08bound", conv.convert("Test 08Bound"));
-        assert_eq!("a_8_a_a_8a", conv.convert("a8aA8A"));
+        assert_eq!("color", conv.convert("kColor"));
+        assert_eq!("color", conv.convert("EColor"));
     }
 
     #[test]
-    fn add_boundary() {
+    fn acronym_min_len() {
+        let conv = Converter::new().to_case(Case::Snake).set_acronym_min_len(2);
+        assert_eq!("a_good_example", conv.convert("AGoodExample"));
+        assert_eq!("io_stream", conv.convert("IOStream"));
+
+        let conv = conv.set_acronym_min_len(4);
+        assert_eq!("agood_example", conv.convert("AGoodExample"));
+        assert_eq!("iostream", conv.convert("IOStream"));
+        assert_eq!("xml_http_request", conv.convert("XMLHttpRequest"));
+        assert_eq!("my_iostream", conv.convert("myIOStream"));
+
+        let conv = conv.with_acronyms(&["io", "A"]);
+        assert_eq!("io_stream", conv.convert("IOStream"));
+        assert_eq!("a_good_example", conv.convert("AGoodExample"));
+    }
+
+    #[test]
+    fn convert_detailed_words_join_to_string() {
+        let conv = Converter::new().to_case(Case::UpperSnake);
+        for s in ["myVarName", "XMLHttpRequest 2", "", "single"] {
+            let (words, joined) = conv.convert_detailed(s);
+            assert_eq!(words.join("_"), joined);
+            assert_eq!(conv.convert(s), joined);
+        }
+    }
+
+    #[test]
+    fn capitalize_after_digits() {
+        let conv = Converter::new()
+            .from_case(Case::Camel)
+            .to_case(Case::Pascal);
+        assert_eq!("User2Name", conv.convert("user2name"));
+
+        let conv = conv
+            .remove_boundary(Boundary::DigitLower)
+            .capitalize_after_digits();
+        assert_eq!("User2Name", conv.convert("user2name"));
+        assert_eq!("Mp3Player4You", conv.convert("mp3player4you"));
+    }
+
+    #[test]
+    fn capitalize_after_digits_keeps_quoted() {
+        let conv = Converter::new()
+            .set_boundaries(&[Boundary::Space])
+            .to_case(Case::Snake)
+            .preserve_quoted()
+            .capitalize_after_digits();
+        assert_eq!("a1B_\"a1b\"", conv.convert("a1b \"a1b\""));
+    }
+
+    #[test]
+    fn boundary_fn_runtime_delim() {
+        let delim = String::from("||");
+        let conv = Converter::new()
+            .from_case(Case::Camel)
+            .add_boundary_fn(move |s| s.starts_with(delim.as_str()).then_some(delim.len()))
+            .to_case(Case::Kebab);
+        assert_eq!("left-side-right-side", conv.convert("leftSide||rightSide"));
+    }
+
+    #[test]
+    fn boundary_fn_with_set_boundaries() {
+        let conv = Converter::new()
+            .add_boundary_fn(|s| s.starts_with('.').then_some(1))
+            .set_boundaries(&[Boundary::Hyphen])
+            .to_case(Case::Snake);
+        assert_eq!("a_b_c_d", conv.convert("a.b-c.d"));
+        assert_eq!("ab_c", conv.convert("aB.c"));
+    }
+
+    #[test]
+    fn boundary_fn_without_consuming() {
+        let conv = Converter::new()
+            .set_boundaries(&[])
+            .add_boundary_fn(|s| s.starts_with('#').then_some(0))
+            .to_case(Case::Snake);
+        assert_eq!("tag_#one_#two", conv.convert("tag#one#two"));
+    }
+
+    #[test]
+    fn boundary_fn_ignores_invalid_length() {
+        let conv = Converter::new()
+            .set_boundaries(&[])
+            .add_boundary_fn(|s| s.starts_with('é').then_some(1))
+            .to_case(Case::Snake);
+        assert_eq!("aéb", conv.convert("aéb"));
+    }
+
+    #[cfg(feature = "diacritics")]
+    #[test]
+    fn strip_diacritics() {
+        let conv = Converter::new().to_case(Case::Kebab).strip_diacritics();
+        assert_eq!("cafe-munchen", conv.convert("café_münchen"));
+
+        let conv = Converter::new().to_case(Case::Pascal).strip_diacritics();
+        assert_eq!("EleveCrepeSoufflee", conv.convert("élève crêpe soufflée"));
+        assert_eq!("Straße", conv.convert("straße"));
+    }
+
+    #[test]
+    fn keep_numbers() {
+        let conv = Converter::new()
+            .set_boundaries(&[Boundary::from_delim("."), Boundary::Hyphen])
+            .to_case(Case::Snake)
+            .keep_numbers();
+        assert_eq!("pi_3.14_val", conv.convert("pi3.14val"));
+        assert_eq!("temp_-5_deg", conv.convert("temp-5deg"));
+        assert_eq!("v_1.2_3", conv.convert("v1.2.3"));
+        assert_eq!("1_2", conv.convert("1-2"));
+        assert_eq!("end", conv.convert("end."));
+    }
+
+    #[test]
+    fn comma_in_number() {
+        let conv = Converter::new().to_case(Case::Snake);
+        assert_eq!("10,000_days", conv.convert("10,000Days"));
+        assert_eq!("10,000_days", conv.keep_numbers().convert("10,000Days"));
+    }
+
+    #[test]
+    fn strip_symbols() {
+        let conv = Converter::new().to_case(Case::Snake).strip_symbols();
+        assert_eq!("10000_days", conv.convert("10,000Days"));
+        assert_eq!("10000_days", conv.keep_numbers().convert("10,000Days"));
+
+        let conv = Converter::new().to_case(Case::Camel).strip_symbols();
+        assert_eq!("tisTheSeason", conv.convert("'tis the season!"));
+        assert_eq!("caféAuLait", conv.convert("(café) & au lait"));
+    }
+
+    #[test]
+    fn slugify() {
+        let s = "Hello, World!  Test";
+        assert_eq!("hello-world-test", Converter::new().slugify("-").convert(s));
+        assert_eq!("hello_world_test", Converter::new().slugify("_").convert(s));
+
+        let conv = Converter::new().slugify("-");
+        assert_eq!("über-straße", conv.convert("ÜBER Straße"));
+        assert_eq!("a-b", conv.convert("-- a !! b --"));
+        assert_eq!("xml-http-request", conv.convert("XMLHttpRequest"));
+        assert_eq!("", conv.convert("?!"));
+    }
+
+    #[test]
+    fn strip_symbols_keeps_quoted() {
         let conv = Converter::new()
-            .from_case(Case::Snake)
             .to_case(Case::Kebab)
-            .add_boundary(Boundary::LowerUpper);
-        assert_eq!("word-word-word", conv.convert("word_wordWord"));
+            .preserve_quoted()
+            .strip_symbols();
+        assert_eq!("say-\"Hi!\"-now", conv.convert("say \"Hi!\" now!"));
     }
 
     #[test]
-    fn add_boundaries() {
+    fn keep_numbers_and_quoted() {
         let conv = Converter::new()
-            .from_case(Case::Snake)
             .to_case(Case::Kebab)
-            .add_boundaries(&[Boundary::LowerUpper, Boundary::UpperLower]);
-        assert_eq!("word-word-w-ord", conv.convert("word_wordWord"));
+            .keep_numbers()
+            .preserve_quoted();
+        assert_eq!("a-2.5-\"b1.5\"-c", conv.convert("a2.5\"b1.5\"c"));
     }
 
     #[test]
-    fn reuse_after_change() {
-        let conv = Converter::new().from_case(Case::Snake).to_case(Case::Kebab);
-        assert_eq!("word-wordword", conv.convert("word_wordWord"));
+    fn number_spans() {
+        assert_eq!(Some((2, 6)), number_span("pi3.14val", 0, true, false));
+        assert_eq!(Some((4, 6)), number_span("temp-5deg", 0, true, false));
+        assert_eq!(Some((2, 3)), number_span("1-2", 1, true, false));
+        assert_eq!(Some((1, 2)), number_span("v1.", 0, true, false));
+        assert_eq!(None, number_span("no digits - here", 0, true, false));
+        assert_eq!(Some((0, 9)), number_span("1,000,000", 0, true, false));
+        assert_eq!(Some((0, 2)), number_span("10,00", 0, true, false));
+        assert_eq!(Some((0, 1)), number_span("1,0000", 0, true, false));
+        assert_eq!(Some((0, 1)), number_span("1_000", 0, true, false));
+        assert_eq!(Some((0, 5)), number_span("1_000", 0, false, true));
+        assert_eq!(Some((0, 8)), number_span("3.14_159", 0, false, true));
+        assert_eq!(Some((0, 1)), number_span("1__000", 0, false, true));
+        assert_eq!(Some((0, 1)), number_span("1_x", 0, false, true));
+        assert_eq!(Some((5, 6)), number_span("temp-5deg", 0, false, false));
+        assert_eq!(Some((5, 10)), number_span("item-1_000", 0, false, true));
+    }
 
-        let conv = conv.add_boundary(Boundary::LowerUpper);
-        assert_eq!("word-word-word", conv.convert("word_wordWord"));
+    #[test]
+    fn number_aware() {
+        let conv = Converter::new().to_case(Case::Kebab);
+        assert_eq!("value-1-000", conv.convert("value1_000"));
+
+        let conv = conv.number_aware(true);
+        assert_eq!("value-1_000", conv.convert("value1_000"));
+        assert_eq!("value-1,000", conv.convert("value_1,000"));
+        let dotted = conv.clone().add_boundary(Boundary::from_delim("."));
+        assert_eq!("pi-3.14", dotted.convert("pi3.14"));
+        assert_eq!("my-var-2", conv.convert("my_var_2"));
+        assert_eq!("a-1-b", conv.convert("a_1_b"));
+        assert_eq!("my-var-2", conv.convert("my-var-2"));
+        assert_eq!("item-5", conv.convert("item-5"));
+        assert_eq!(
+            "item_5",
+            conv.clone().to_case(Case::Snake).convert("item-5")
+        );
+        assert_eq!("range-1,000-2,000", conv.convert("range-1,000-2,000"));
+        let signed = conv.clone().keep_numbers().to_case(Case::Snake);
+        assert_eq!("temp_-5_deg", signed.convert("temp-5deg"));
+
+        let conv = conv.number_aware(false);
+        assert_eq!("value-1-000", conv.convert("value1_000"));
+    }
+
+    #[test]
+    fn acronyms_whole_word() {
+        let conv = Converter::new()
+            .to_case(Case::Pascal)
+            .with_acronyms(&["IO", "iOS"]);
+        assert_eq!("IOStream", conv.convert("io_stream"));
+        assert_eq!("IonStream", conv.convert("ion_stream"));
+        assert_eq!("PortIO", conv.convert("port_io"));
+        assert_eq!("iOSApp", conv.convert("ios app"));
+    }
+
+    #[test]
+    fn acronyms_keep_lowercase_words() {
+        let conv = Converter::new().with_acronyms(&["IO"]).to_case(Case::Camel);
+        assert_eq!("ioStreamIO", conv.convert("IO_STREAM_IO"));
+
+        let conv = conv.to_case(Case::Snake);
+        assert_eq!("io_stream", conv.convert("IOStream"));
+
+        let conv = conv.to_case(Case::Title);
+        assert_eq!("IO Stream", conv.convert("io stream"));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn seeded_random() {
+        let conv = Converter::new().to_case(Case::PseudoRandom).set_seed(1);
+        let first = conv.convert("a long sentence to make random");
+        assert_eq!(first, conv.convert("a long sentence to make random"));
+        assert_eq!("a long sentence to make random", first.to_lowercase());
+
+        let other = Converter::new().to_case(Case::PseudoRandom).set_seed(2);
+        assert_ne!(first, other.convert("a long sentence to make random"));
+    }
+
+    #[test]
+    fn camel_lower_first_letter_only() {
+        let conv = Converter::new()
+            .to_case(Case::Camel)
+            .camel_lower_first_letter_only();
+        assert_eq!("hTTPServer", conv.convert("HTTPServer"));
+        assert_eq!("myVarName", conv.convert("my_var_name"));
+        assert_eq!("xMLHttpRequest", conv.convert("XMLHttpRequest"));
+
+        let conv = conv.to_case(Case::Pascal);
+        assert_eq!("HttpServer", conv.convert("HTTPServer"));
+    }
+
+    #[test]
+    fn clone_converts_the_same() {
+        let conv = Converter::new()
+            .from_case(Case::Camel)
+            .to_case(Case::Snake)
+            .set_joiner(|words| words.join("__"))
+            .preserve_quoted();
+        let cloned = conv.clone();
+        for s in ["myVarName", "say \"Hi There\"", ""] {
+            assert_eq!(conv.convert(s), cloned.convert(s));
+        }
+    }
+
+    #[test]
+    fn changing_clone_keeps_original() {
+        let conv = Converter::new().from_case(Case::Camel).to_case(Case::Snake);
+        let kebab = conv
+            .clone()
+            .to_case(Case::Kebab)
+            .add_boundary(Boundary::Space);
+        assert_eq!("my_var name", conv.convert("myVar name"));
+        assert_eq!("my-var-name", kebab.convert("myVar name"));
+        assert_eq!(Case::Camel.boundaries(), conv.boundaries);
+    }
+
+    #[test]
+    fn debug_shows_fields() {
+        let conv = Converter::new()
+            .set_boundaries(&[Boundary::Hyphen])
+            .set_delim("_");
+        assert_eq!(
+            r#"Converter { boundaries: [Hyphen], pattern: None, delim: "_", .. }"#,
+            format!("{:?}", conv)
+        );
+    }
+
+    #[test]
+    fn title_with_word_sets() {
+        let conv = Converter::new()
+            .title_with(&["AND"], &["x", "y"])
+            .to_case(Case::Title);
+        assert_eq!("War and Peace", conv.convert("war and peace"));
+        assert_eq!("and X and Y", conv.convert("and x and y"));
+
+        let conv = conv.to_case(Case::Snake);
+        assert_eq!("X_and_Y", conv.convert("x AND y"));
+    }
+
+    #[test]
+    fn owned_case_round_trip() {
+        let conv = Converter::new()
+            .set_boundaries(&[Boundary::Underscore, Boundary::LowerUpper])
+            .set_pattern(Pattern::Capital)
+            .set_delim("~");
+        let expected = conv.convert("my_varName");
+
+        let case = conv.into_case();
+        let rebuilt = case.converter();
+        assert_eq!(expected, rebuilt.convert("my_varName"));
+        assert_eq!(case, rebuilt.into_case());
+    }
+
+    #[test]
+    fn preserve_quoted() {
+        let conv = Converter::new().to_case(Case::Snake);
+        assert_eq!(
+            r#"prefix_"leave_me_alone"_suffix"#,
+            conv.convert(r#"prefix_"LeaveMeAlone"_suffix"#)
+        );
+
+        let conv = conv.preserve_quoted();
+        assert_eq!(
+            r#"prefix_"LeaveMeAlone"_suffix"#,
+            conv.convert(r#"prefix_"LeaveMeAlone"_suffix"#)
+        );
+        assert_eq!(
+            r#""Keep Me"_and_me_"Me_Too""#,
+            conv.convert(r#""Keep Me"AndMe"Me_Too""#)
+        );
+    }
+
+    #[test]
+    fn preserve_quoted_unmatched() {
+        let conv = Converter::new().to_case(Case::Kebab).preserve_quoted();
+        assert_eq!(r#"one-"two-three"#, conv.convert(r#"one "TwoThree"#));
     }
 
     #[test]
@@ -190,16 +190,102 @@
 //! ```
 //! This will add two additional cases: Random and PseudoRandom.  You can read about their
 //! construction in the [Case enum](enum.Case.html).
+//!
+//! # Diacritics Feature
+//!
+//! The _diacritics_ feature adds `Converter::strip_diacritics`, which turns letters like `é`
+//! into `e`.  It requires the `unicode-normalization` crate.
+//! ```toml
+//! [dependencies]
+//! convert_case = { version = "^0.6.0", features = ["diacritics"] }
+//! ```
+//!
+//! # Serde Feature
+//!
+//! The _serde_ feature implements `Serialize` and `Deserialize` for [`Case`], using the
+//! names of each case, like `"snake"` or `"upper_kebab"`.
+//! ```toml
+//! [dependencies]
+//! convert_case = { version = "^0.6.0", features = ["serde"] }
+//! ```
+//!
+//! # No Std
+//!
+//! The _std_ feature is enabled by default.  Without it, the library is `no_std` and only
+//! needs the `alloc` crate, so `to_case`, `Converter`, `split`, and the rest of the API can be
+//! used on embedded targets.
+//! ```toml
+//! [dependencies]
+//! convert_case = { version = "^0.6.0", default-features = false }
+//! ```
+//! A few things need `std`:
+//! * [`Converter::convert_path_filename`], which works with `std::path`,
+//! * the `std::error::Error` implementation of [`ParseCaseError`],
+//! * and the _random_, _diacritics_, and _serde_ features, which turn on _std_.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 mod case;
 mod converter;
 mod pattern;
 mod segmentation;
 
-pub use case::Case;
+pub use case::{Case, CaseDiff, OwnedCase, ParseCaseError};
 pub use converter::Converter;
-pub use pattern::Pattern;
-pub use segmentation::Boundary;
+pub use pattern::{Locale, Pattern};
+pub use segmentation::{split, split_iter, Boundary};
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Re-exports the most commonly used items, so one glob import covers most conversions.
+/// ```
+/// use convert_case::prelude::*;
+///
+/// assert_eq!("my_var_name", "myVarName".to_case(Case::Snake));
+/// assert_eq!(vec!["my", "Var"], split("myVar", &[Boundary::LowerUpper]));
+///
+/// let conv = Converter::new().set_pattern(Pattern::Uppercase).set_delim(".");
+/// assert_eq!("MY.VAR", conv.convert("my var"));
+/// ```
+pub mod prelude {
+    pub use crate::{split, Boundary, Case, Casing, Converter, Pattern};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+    pub use alloc::vec::Vec;
+}
+
+/// Converts a list of identifiers, like a struct's field names, into the given case.  Returns
+/// a `Vec<String>` with the names in the order given.
+/// ```
+/// use convert_case::{field_names_in_case, Case};
+///
+/// assert_eq!(
+///     vec!["first_name", "last_name", "user_id"],
+///     field_names_in_case!(Case::Snake; firstName, lastName, userID)
+/// );
+/// ```
+#[macro_export]
+macro_rules! field_names_in_case {
+    ($case:expr; $($field:ident),* $(,)?) => {{
+        let conv = $crate::Converter::new().to_case($case);
+        let fields: &[&str] = &[$(stringify!($field)),*];
+        fields
+            .iter()
+            .map(|field| conv.convert(field))
+            .collect::<$crate::__private::Vec<$crate::__private::String>>()
+    }};
+}
 
 /// Describes items that can be converted into a case.  This trait is used
 /// in conjunction with the [`StateConverter`] struct which is returned from a couple
@@ -221,6 +307,155 @@ pub trait Casing<T: AsRef<str>> {
     /// ```
     fn to_case(&self, case: Case) -> String;
 
+    /// Convert the string into the given case like [`to_case`](Casing::to_case), but
+    /// borrow `self` when it is already in that case.  A new `String` is only returned
+    /// when the conversion changed the string.
+    /// ```
+    /// use std::borrow::Cow;
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert!(matches!("already_snake".to_case_cow(Case::Snake), Cow::Borrowed(_)));
+    /// assert_eq!(
+    ///     Cow::<str>::Owned("now_snake".to_string()),
+    ///     "nowSnake".to_case_cow(Case::Snake)
+    /// );
+    /// ```
+    fn to_case_cow(&self, case: Case) -> Cow<'_, str>;
+
+    /// Convert the string into the case named by `case`, which is parsed like
+    /// [`Case::from_str`](core::str::FromStr::from_str).  This is the way to convert into a case
+    /// chosen at runtime from code that compiles with or without the "random" feature:
+    /// `Case::Random` and `Case::PseudoRandom` only exist with the feature, but their names
+    /// can always be passed here.  Without the feature, converting into them returns an error
+    /// whose [`required_feature`](ParseCaseError::required_feature) is `"random"`.
+    /// ```
+    /// use convert_case::Casing;
+    ///
+    /// assert_eq!(Ok("my_var".to_string()), "myVar".try_to_case("snake"));
+    /// assert!("myVar".try_to_case("snek").is_err());
+    ///
+    /// match "myVar".try_to_case("random") {
+    ///     Ok(random) => assert_eq!("my var", random.to_lowercase()),
+    ///     Err(err) => assert_eq!(Some("random"), err.required_feature()),
+    /// }
+    /// ```
+    fn try_to_case(&self, case: &str) -> Result<String, ParseCaseError>;
+
+    /// Convert the string into title case, but lowercase the given stop words unless they are
+    /// the first or last word.  Stop words are matched ignoring case.  The string is split on
+    /// [`Boundary::defaults()`].
+    /// ```
+    /// use convert_case::Casing;
+    ///
+    /// let stop_words = ["a", "an", "the", "to", "for", "of", "and"];
+    /// assert_eq!(
+    ///     "The Lord of the Rings",
+    ///     "the lord of the rings".to_title_with_stopwords(&stop_words)
+    /// );
+    /// ```
+    fn to_title_with_stopwords(&self, stop_words: &[&str]) -> String;
+
+    /// Convert the string into the given case like [`to_case`](Casing::to_case), and also
+    /// return the number of graphemes in the result.  This is the number of user-perceived
+    /// characters, not the number of bytes.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(("über_größe".to_string(), 10), "ÜberGröße".to_case_len(Case::Snake));
+    /// ```
+    fn to_case_len(&self, case: Case) -> (String, usize);
+
+    /// Convert the string into each of the given cases, returning the results in the same
+    /// order.  The string is split into words once, and each case only applies its pattern
+    /// and delimiter, so this is faster than calling [`to_case`](Casing::to_case) for each.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     vec!["my_var_name", "MyVarName", "my-var-name"],
+    ///     "myVarName".to_case_multi(&[Case::Snake, Case::Pascal, Case::Kebab])
+    /// );
+    /// ```
+    fn to_case_multi(&self, cases: &[Case]) -> Vec<String>;
+
+    /// Convert the string into the given case like [`to_case`](Casing::to_case), also
+    /// returning whether the [`Acronym`](Boundary::Acronym) boundary split the string
+    /// anywhere.  This shows when acronym handling changed the result, and removing the
+    /// boundary would give a different one.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let (s, acronyms) = "IOStream".to_case_report_acronyms(Case::Snake);
+    /// assert_eq!("io_stream", s);
+    /// assert!(acronyms);
+    ///
+    /// let (s, acronyms) = "ioStream".to_case_report_acronyms(Case::Snake);
+    /// assert_eq!("io_stream", s);
+    /// assert!(!acronyms);
+    /// ```
+    fn to_case_report_acronyms(&self, case: Case) -> (String, bool);
+
+    /// Splits the string using the boundaries of `from` and joins the words with `to_delim`,
+    /// keeping the case of every letter.  This is the same as converting with a
+    /// [`Converter`] that has no pattern.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!("myCrazy_word_LIST", "myCrazy-word-LIST".redelimit(Case::Kebab, "_"));
+    /// assert_eq!("XML.Http.Request", "XMLHttpRequest".redelimit(Case::Pascal, "."));
+    /// ```
+    fn redelimit(&self, from: Case, to_delim: &str) -> String;
+
+    /// Returns the number of words the string is split into using the boundaries of `case`,
+    /// without converting it.  Empty words, like those between repeated delimiters, are not
+    /// counted.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(3, "myVarName".word_count(Case::Camel));
+    /// assert_eq!(2, "__my__var".word_count(Case::Snake));
+    /// assert_eq!(0, "".word_count(Case::Snake));
+    /// ```
+    fn word_count(&self, case: Case) -> usize;
+
+    /// Convert the string into the given case like [`to_case`](Casing::to_case), using the
+    /// case mappings of `locale`.
+    /// ```
+    /// use convert_case::{Case, Casing, Locale};
+    ///
+    /// assert_eq!("istanbul", "İSTANBUL".to_case_with_locale(Case::Lower, Locale::Turkish));
+    /// assert_eq!("i̇stanbul", "İSTANBUL".to_case_with_locale(Case::Lower, Locale::Default));
+    /// ```
+    fn to_case_with_locale(&self, case: Case, locale: Locale) -> String;
+
+    /// Convert the string into the given case like [`to_case`](Casing::to_case), seeding the
+    /// random number generator used by `Case::Random` and `Case::PseudoRandom`.  The same
+    /// seed always gives the same output.  Only available in the "random" feature.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "hello world".to_case_with_seed(Case::Random, 42),
+    ///     "hello world".to_case_with_seed(Case::Random, 42)
+    /// );
+    /// ```
+    #[cfg(feature = "random")]
+    fn to_case_with_seed(&self, case: Case, seed: u64) -> String;
+
+    /// Convert the string into the given case like [`to_case`](Casing::to_case), but
+    /// write the result into `buf` instead of allocating a new `String`.  The buffer is
+    /// cleared first.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let mut buf = String::new();
+    /// for (name, expected) in [("myVar", "MY_VAR"), ("other var", "OTHER_VAR")] {
+    ///     name.to_case_into(Case::UpperSnake, &mut buf);
+    ///     assert_eq!(expected, buf);
+    /// }
+    /// ```
+    fn to_case_into(&self, case: Case, buf: &mut String);
+
     /// Start the case conversion by storing the boundaries associated with the given case.
     /// ```
     /// use convert_case::{Case, Casing};
@@ -233,7 +468,36 @@ pub trait Casing<T: AsRef<str>> {
     /// );
     /// ```
     #[allow(clippy::wrong_self_convention)]
-    fn from_case(&self, case: Case) -> StateConverter<T>;
+    fn from_case(&self, case: Case) -> StateConverter<'_, T>;
+
+    /// Start the case conversion like [`from_case`](Casing::from_case), but in debug builds
+    /// panic when the string is parsed as a single word even though the default boundaries
+    /// would split it.  This catches using the wrong case, like parsing a kebab case string
+    /// as snake case.  In release builds it is the same as `from_case`.  To report the
+    /// problem some other way, use [`check_from_case`](Casing::check_from_case).
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "my_kebab",
+    ///     "my-kebab".from_case_checked(Case::Kebab).to_case(Case::Snake)
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_case_checked(&self, case: Case) -> StateConverter<'_, T>;
+
+    /// Describes the problem if the string is a single word when split from `case`, but more
+    /// than one word when split with the default boundaries.  This is the check that
+    /// [`from_case_checked`](Casing::from_case_checked) makes, returned so the caller can
+    /// decide whether to log it, return an error, or ignore it.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// let warning = "my-kebab".check_from_case(Case::Snake).unwrap();
+    /// assert!(warning.contains("it looks like Kebab case"));
+    /// assert_eq!(None, "my-kebab".check_from_case(Case::Kebab));
+    /// ```
+    fn check_from_case(&self, case: Case) -> Option<String>;
 
     /// Creates a `StateConverter` struct initialized with the boundaries
     /// provided.
@@ -247,20 +511,54 @@ pub trait Casing<T: AsRef<str>> {
     ///         .to_case(Case::Snake)
     /// );
     /// ```
-    fn with_boundaries(&self, bs: &[Boundary]) -> StateConverter<T>;
+    fn with_boundaries(&self, bs: &[Boundary]) -> StateConverter<'_, T>;
 
-    /// Determines if `self` is of the given case.  This is done simply by applying
-    /// the conversion and seeing if the result is the same.
+    /// Creates a `StateConverter` struct initialized with the boundaries found in the
+    /// example `pattern`, as given by [`Boundary::list_from`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "7empest By Tool",
+    ///     "7empest byTool"
+    ///         .from_pattern("aA ")
+    ///         .to_case(Case::Title)
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_pattern(&self, pattern: &str) -> StateConverter<'_, T>;
+
+    /// Determines if `self` is of the given case.  This is done by converting from and to
+    /// the case and seeing if the result is the same.  The string is split using the
+    /// boundaries of `case` along with the delimiter boundaries `Hyphen`, `Underscore`,
+    /// and `Space`, so digits next to letters are fine, but a string delimited some other
+    /// way is not mistaken as a single word.
+    ///
+    /// A single word without letters, like `123`, is in any case that doesn't uppercase it.
+    /// Symbols other than delimiters, like `$` or `!`, are part of the words they touch.
     /// ```
     /// use convert_case::{Case, Casing};
     /// 
     /// assert!( "kebab-case-string".is_case(Case::Kebab));
     /// assert!( "Train-Case-String".is_case(Case::Train));
+    /// assert!( "UPPER_CASE_WITH_DIGIT1".is_case(Case::UpperSnake));
     ///
     /// assert!(!"kebab-case-string".is_case(Case::Snake));
     /// assert!(!"kebab-case-string".is_case(Case::Train));
     /// ```
     fn is_case(&self, case: Case) -> bool;
+
+    /// Determines if `self` is of the given case when split using only the boundaries `bs`.
+    /// Unlike [`is_case`](Casing::is_case), no other boundaries are added, so this answers
+    /// questions like "is this upper snake case if only underscores separate words?"
+    /// ```
+    /// use convert_case::{Boundary, Case, Casing};
+    ///
+    /// assert!("ABC_123".is_case_with_boundaries(Case::UpperSnake, &[Boundary::Underscore]));
+    /// assert!("MyVar".is_case_with_boundaries(Case::Pascal, &[Boundary::LowerUpper]));
+    /// assert!(!"MyVar".is_case_with_boundaries(Case::Pascal, &[Boundary::Underscore]));
+    /// ```
+    fn is_case_with_boundaries(&self, case: Case, bs: &[Boundary]) -> bool;
 }
 
 impl<T: AsRef<str>> Casing<T> for T
@@ -271,17 +569,289 @@ where
         StateConverter::new(self).to_case(case)
     }
 
-    fn with_boundaries(&self, bs: &[Boundary]) -> StateConverter<T> {
+    fn to_case_cow(&self, case: Case) -> Cow<'_, str> {
+        Converter::new().to_case(case).convert_cow(self.as_ref())
+    }
+
+    fn try_to_case(&self, case: &str) -> Result<String, ParseCaseError> {
+        Ok(self.to_case(case.parse()?))
+    }
+
+    fn to_case_with_locale(&self, case: Case, locale: Locale) -> String {
+        Converter::new()
+            .to_case(case)
+            .set_locale(locale)
+            .convert(self)
+    }
+
+    #[cfg(feature = "random")]
+    fn to_case_with_seed(&self, case: Case, seed: u64) -> String {
+        Converter::new().to_case(case).set_seed(seed).convert(self)
+    }
+
+    fn to_title_with_stopwords(&self, stop_words: &[&str]) -> String {
+        let words = split(self, &Boundary::defaults());
+        pattern::title_with_stop_words(&words, stop_words, Default::default()).join(" ")
+    }
+
+    fn to_case_len(&self, case: Case) -> (String, usize) {
+        let converted = self.to_case(case);
+        let len = converted.graphemes(true).count();
+        (converted, len)
+    }
+
+    fn redelimit(&self, from: Case, to_delim: &str) -> String {
+        Converter::new()
+            .from_case(from)
+            .set_delim(to_delim)
+            .convert(self)
+    }
+
+    fn word_count(&self, case: Case) -> usize {
+        // split_iter borrows the case's static boundaries, so this doesn't allocate
+        case.split_iter(self).count()
+    }
+
+    fn to_case_multi(&self, cases: &[Case]) -> Vec<String> {
+        let words = split(self, &Boundary::defaults());
+        cases
+            .iter()
+            .map(|&case| Converter::new().to_case(case).join_words(&words))
+            .collect()
+    }
+
+    fn to_case_report_acronyms(&self, case: Case) -> (String, bool) {
+        // an acronym split is never at the same place as another default boundary, so the
+        // words only change when it is removed if it split the string
+        let defaults = Boundary::defaults();
+        let without_acronym = Boundary::difference(&defaults, &[Boundary::Acronym]);
+        let split_on_acronym = split(self, &defaults) != split(self, &without_acronym);
+        (self.to_case(case), split_on_acronym)
+    }
+
+    fn to_case_into(&self, case: Case, buf: &mut String) {
+        Converter::new().to_case(case).convert_into(self, buf)
+    }
+
+    fn with_boundaries(&self, bs: &[Boundary]) -> StateConverter<'_, T> {
         StateConverter::new(self).with_boundaries(bs)
     }
 
-    fn from_case(&self, case: Case) -> StateConverter<T> {
+    fn from_pattern(&self, pattern: &str) -> StateConverter<'_, T> {
+        StateConverter::new(self).with_boundaries(&Boundary::list_from(pattern))
+    }
+
+    fn from_case(&self, case: Case) -> StateConverter<'_, T> {
         StateConverter::new_from_case(self, case)
     }
 
+    fn from_case_checked(&self, case: Case) -> StateConverter<'_, T> {
+        #[cfg(debug_assertions)]
+        if let Some(warning) = self.check_from_case(case) {
+            panic!("{}", warning);
+        }
+        self.from_case(case)
+    }
+
+    fn check_from_case(&self, case: Case) -> Option<String> {
+        single_word_warning(self.as_ref(), case)
+    }
+
     fn is_case(&self, case: Case) -> bool {
-        &self.to_case(case) == self
+        let mut boundaries = case.boundaries();
+        boundaries.extend(Boundary::delims());
+        &self.with_boundaries(&boundaries).to_case(case) == self
+    }
+
+    fn is_case_with_boundaries(&self, case: Case, bs: &[Boundary]) -> bool {
+        &self.with_boundaries(bs).to_case(case) == self
+    }
+}
+
+/// Describes optional strings that can be converted into a case.  This saves mapping over
+/// an `Option` just to call [`Casing::to_case`].
+///
+/// Implemented for `Option<T>` where `T` is any string type that can be converted with
+/// `Casing`.
+/// ```
+/// use convert_case::{Case, CasingOpt};
+///
+/// assert_eq!(Some("foo_bar".to_string()), Some("fooBar").to_case(Case::Snake));
+/// assert_eq!(None, None::<String>.to_case(Case::Snake));
+/// ```
+pub trait CasingOpt {
+    /// Convert the string, if there is one, into the given case.
+    /// ```
+    /// use convert_case::{Case, CasingOpt};
+    ///
+    /// let name: Option<String> = Some("first name".to_string());
+    /// assert_eq!(Some("FirstName".to_string()), name.to_case(Case::Pascal));
+    /// ```
+    fn to_case(&self, case: Case) -> Option<String>;
+}
+
+impl<T: AsRef<str>> CasingOpt for Option<T> {
+    fn to_case(&self, case: Case) -> Option<String> {
+        self.as_ref().map(|s| s.as_ref().to_case(case))
+    }
+}
+
+/// Describes characters that can be converted into a case, treating the character as a
+/// one letter word.  `char` doesn't implement `AsRef<str>`, so it can't use [`Casing`].
+/// ```
+/// use convert_case::{Case, CasingChar};
+///
+/// assert_eq!("X", 'x'.to_case(Case::Upper));
+/// assert_eq!("Ä", 'ä'.to_case(Case::Title));
+/// ```
+pub trait CasingChar {
+    /// Convert the character into the given case, returning a `String` since some
+    /// characters, like `ß`, become more than one character.
+    /// ```
+    /// use convert_case::{Case, CasingChar};
+    ///
+    /// assert_eq!("SS", 'ß'.to_case(Case::Upper));
+    /// ```
+    fn to_case(&self, case: Case) -> String;
+}
+
+impl CasingChar for char {
+    fn to_case(&self, case: Case) -> String {
+        Converter::new()
+            .to_case(case)
+            .convert(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+/// Describes iterators of strings whose items can all be converted into a case.  A single
+/// [`Converter`] is built and reused for every item.
+///
+/// Implemented for any iterator whose items are string types, like `&str`, `String`, and
+/// `&String`.
+/// ```
+/// use convert_case::{Case, CasingIter};
+///
+/// let names = vec!["firstName".to_string(), "Last Name".to_string()];
+/// assert_eq!(
+///     vec!["first_name", "last_name"],
+///     names.iter().to_case(Case::Snake).collect::<Vec<_>>()
+/// );
+/// ```
+pub trait CasingIter: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Convert each string into the given case.
+    /// ```
+    /// use convert_case::{Case, CasingIter};
+    ///
+    /// let mut names = ["one_two", "threeFour"].into_iter().to_case(Case::Kebab);
+    /// assert_eq!(Some("one-two".to_string()), names.next());
+    /// assert_eq!(Some("three-four".to_string()), names.next());
+    /// assert_eq!(None, names.next());
+    /// ```
+    fn to_case(self, case: Case) -> impl Iterator<Item = String>;
+}
+
+impl<I> CasingIter for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    fn to_case(self, case: Case) -> impl Iterator<Item = String> {
+        let conv = Converter::new().to_case(case);
+        self.map(move |s| conv.convert(s))
+    }
+}
+
+/// Whether two identifiers have the same words, no matter what case they are written in.  Both
+/// are split with the [default boundaries](Boundary::defaults) and the words are compared
+/// ignoring case, so only a rename, not a change of style, makes them unequal.
+/// ```
+/// use convert_case::identifiers_equal_ignoring_case_style;
+///
+/// assert!(identifiers_equal_ignoring_case_style("getUserName", "get_user_name"));
+/// assert!(identifiers_equal_ignoring_case_style("HTTP_SERVER", "HttpServer"));
+/// assert!(!identifiers_equal_ignoring_case_style("getUserName", "get_user_id"));
+/// ```
+pub fn identifiers_equal_ignoring_case_style(a: &str, b: &str) -> bool {
+    identifier_differences(a, b).is_empty()
+}
+
+/// Returns the words that differ between two identifiers, ignoring the case they are written
+/// in, as pairs of the word from `a` and the word from `b` at the same position.  When one
+/// identifier has more words than the other, the missing words are `None`.  The list is empty
+/// when [`identifiers_equal_ignoring_case_style`] is true.
+/// ```
+/// use convert_case::identifier_differences;
+///
+/// assert_eq!(
+///     vec![(Some("Name"), Some("id"))],
+///     identifier_differences("getUserName", "get_user_id")
+/// );
+/// assert_eq!(vec![(None, Some("value"))], identifier_differences("parse", "parse-value"));
+/// ```
+pub fn identifier_differences<'a, 'b>(
+    a: &'a str,
+    b: &'b str,
+) -> Vec<(Option<&'a str>, Option<&'b str>)> {
+    let a_words = split(a, &Boundary::defaults());
+    let b_words = split(b, &Boundary::defaults());
+    (0..a_words.len().max(b_words.len()))
+        .map(|i| (a_words.get(i).copied(), b_words.get(i).copied()))
+        .filter(|(a, b)| match (a, b) {
+            (Some(a), Some(b)) => a.to_lowercase() != b.to_lowercase(),
+            _ => true,
+        })
+        .collect()
+}
+
+/// Wraps a value so that it is converted into `case` when it is displayed.  This is useful
+/// with `format_args!` and logging macros, where the text to convert is formatted lazily.
+/// ```
+/// use convert_case::{cased_display, Case};
+///
+/// let user = "Bar";
+/// let message = format!("{}", cased_display(format_args!("foo{}", user), Case::Snake));
+/// assert_eq!("foo_bar", message);
+/// assert_eq!("Status Code 404", cased_display("statusCode404", Case::Title).to_string());
+/// ```
+pub fn cased_display<T: fmt::Display>(inner: T, case: Case) -> CasedDisplay<T> {
+    CasedDisplay { inner, case }
+}
+
+/// Displays a value converted into a case, created with [`cased_display`].  The value is
+/// formatted into a buffer first, since the whole string is needed to find word boundaries.
+#[derive(Clone, Copy, Debug)]
+pub struct CasedDisplay<T> {
+    inner: T,
+    case: Case,
+}
+
+impl<T: fmt::Display> fmt::Display for CasedDisplay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = String::new();
+        write!(s, "{}", self.inner)?;
+        f.write_str(&Converter::new().to_case(self.case).convert(s))
+    }
+}
+
+/// Describes the problem if `s` is a single word when split from `case`, but more than one
+/// word when split with the default boundaries.
+fn single_word_warning(s: &str, case: Case) -> Option<String> {
+    let words = split(&s, &case.boundaries()).len();
+    let default_words = split(&s, &Boundary::defaults()).len();
+    if words > 1 || default_words <= 1 {
+        return None;
+    }
+    let mut warning = format!(
+        "\"{}\" is one word from {} case, but {} words with the default boundaries",
+        s, case, default_words
+    );
+    if let Some(detected) = Case::detect(s) {
+        warning.push_str(&format!("; it looks like {} case", detected));
     }
+    Some(warning)
 }
 
 /// Holds information about parsing before converting into a case.
@@ -348,32 +918,218 @@ impl<'a, T: AsRef<str>> StateConverter<'a, T> {
     ///     .to_case(Case::Pascal);
     /// assert_eq!("TheHumblingRiver-puscifer", song);  // doesn't split on hyphen `-`
     /// ```
-    pub fn with_boundaries(self, bs: &[Boundary]) -> Self {
-        Self {
-            s: self.s,
-            conv: self.conv.set_boundaries(bs),
-        }
+    pub fn with_boundaries(self, bs: &[Boundary]) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.set_boundaries(bs),
+        }
+    }
+
+    /// Removes any boundaries that were already initialized.  This is particularly useful when a
+    /// case like `Case::Camel` has a lot of associated word boundaries, but you want to exclude
+    /// some.
+    /// ```
+    /// use convert_case::{Boundary, Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "2d_transformation",
+    ///     "2dTransformation"
+    ///         .from_case(Case::Camel)
+    ///         .without_boundaries(&Boundary::digits())
+    ///         .to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn without_boundaries(self, bs: &[Boundary]) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.remove_boundaries(bs),
+        }
+    }
+
+    /// Removes the boundaries between letters and digits, [`Boundary::digits`], so that
+    /// words like "3d", "v2", or "2024" aren't split apart.  This is the same as calling
+    /// `without_boundaries(&Boundary::digits())`.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "transformations_in3d",
+    ///     "TransformationsIn3D"
+    ///         .from_case(Case::Camel)
+    ///         .without_digit_boundaries()
+    ///         .to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn without_digit_boundaries(self) -> Self {
+        self.without_boundaries(&Boundary::digits())
+    }
+
+    /// Removes the boundaries from letters to digits, [`Boundary::letter_digit`], so version
+    /// suffixes like "v2" and "utf8" stay with the word before them.  Delimiters and the
+    /// boundaries from digits to letters are kept, so a word after the digits is still split
+    /// off.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "api_v2",
+    ///     "apiV2".from_case(Case::Camel).keep_version_suffixes().to_case(Case::Snake)
+    /// );
+    /// assert_eq!(
+    ///     "utf8_decoder",
+    ///     "utf8Decoder".from_case(Case::Camel).keep_version_suffixes().to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn keep_version_suffixes(self) -> Self {
+        self.without_boundaries(&Boundary::letter_digit())
+    }
+
+    /// Keeps the whitespace at the start and end of the string as it is.  See
+    /// [`Converter::preserve_edge_whitespace`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "  foo_bar  ",
+    ///     "  fooBar  ".from_case(Case::Camel).preserve_edge_whitespace().to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn preserve_edge_whitespace(self) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.preserve_edge_whitespace(),
+        }
+    }
+
+    /// Removes the `k` from the start of constants like `kDefaultValue`.  See
+    /// [`Converter::strip_k_prefix`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "default_value",
+    ///     "kDefaultValue".from_case(Case::Camel).strip_k_prefix().to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn strip_k_prefix(self) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.strip_k_prefix(),
+        }
+    }
+
+    /// Keeps a single delimiter from the start and end of the string.  See
+    /// [`Converter::preserve_affixes`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "_private",
+    ///     "_private".from_case(Case::Snake).preserve_affixes(true).to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn preserve_affixes(self, preserve: bool) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.preserve_affixes(preserve),
+        }
+    }
+
+    /// Keeps all uppercase words in uppercase where the pattern capitalizes them.  See
+    /// [`Converter::preserve_acronyms`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "xmlHTTP",
+    ///     "XML_HTTP".from_case(Case::UpperSnake).preserve_acronyms().to_case(Case::Camel)
+    /// );
+    /// ```
+    pub fn preserve_acronyms(self) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.preserve_acronyms(),
+        }
+    }
+
+    /// Keeps only the first `max_words` words, followed by `and N more`.  See
+    /// [`Converter::summarize`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "a_b_and_3_more",
+    ///     "a_b_c_d_e".from_case(Case::Snake).summarize(2).to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn summarize(self, max_words: usize) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.summarize(max_words),
+        }
+    }
+
+    /// Quotes the result when it can't be used as a plain SQL identifier.  See
+    /// [`Converter::sql_identifier`].
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(
+    ///     "\"select\"",
+    ///     "select".from_case(Case::Snake).sql_identifier().to_case(Case::Snake)
+    /// );
+    /// ```
+    pub fn sql_identifier(self) -> Self {
+        Self {
+            s: self.s,
+            conv: self.conv.sql_identifier(),
+        }
+    }
+
+    /// Returns the boundaries that will be used to split the string.
+    /// ```
+    /// use convert_case::{Boundary, Case, Casing};
+    ///
+    /// let conv = "myVar2".from_case(Case::Camel).without_boundaries(&Boundary::digits());
+    /// assert_eq!(&[Boundary::LowerUpper, Boundary::Acronym], conv.boundaries());
+    /// ```
+    pub fn boundaries(&self) -> &[Boundary] {
+        &self.conv.boundaries
+    }
+
+    /// Returns the pattern that will mutate the words, which is `None` until
+    /// [`to_case`](StateConverter::to_case) is called.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!(None, "myVar".from_case(Case::Camel).pattern());
+    /// ```
+    pub fn pattern(&self) -> Option<Pattern> {
+        self.conv.pattern
+    }
+
+    /// Returns the delimiter that will join the words, which is empty until
+    /// [`to_case`](StateConverter::to_case) is called.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    ///
+    /// assert_eq!("", "my_var".from_case(Case::Snake).delim());
+    /// ```
+    pub fn delim(&self) -> &str {
+        &self.conv.delim
     }
 
-    /// Removes any boundaries that were already initialized.  This is particularly useful when a
-    /// case like `Case::Camel` has a lot of associated word boundaries, but you want to exclude
-    /// some.
+    /// Returns the words the string is split into, without mutating or joining them.  This
+    /// lets the segmentation be inspected before a case is chosen.
     /// ```
-    /// use convert_case::{Boundary, Case, Casing};
+    /// use convert_case::{Case, Casing};
     ///
-    /// assert_eq!(
-    ///     "2d_transformation",
-    ///     "2dTransformation"
-    ///         .from_case(Case::Camel)
-    ///         .without_boundaries(&Boundary::digits())
-    ///         .to_case(Case::Snake)
-    /// );
+    /// let state = "myVar-name".from_case(Case::Camel);
+    /// assert_eq!(vec!["my", "Var-name"], state.words());
+    /// assert_eq!("my_var-name", state.to_case(Case::Snake));
     /// ```
-    pub fn without_boundaries(self, bs: &[Boundary]) -> Self {
-        Self {
-            s: self.s,
-            conv: self.conv.remove_boundaries(bs),
-        }
+    pub fn words(&self) -> Vec<&'a str> {
+        self.conv.split(self.s.as_ref())
     }
 
     /// Consumes the `StateConverter` and returns the converted string.
@@ -416,6 +1172,8 @@ mod test {
             (Case::Cobol, "MY-VARIABLE-22-NAME"),
             (Case::Toggle, "mY vARIABLE 22 nAME"),
             (Case::Train, "My-Variable-22-Name"),
+            (Case::Path, "my/variable/22/name"),
+            (Case::Dot, "my.variable.22.name"),
             (Case::Alternating, "mY vArIaBlE 22 nAmE"),
         ];
 
@@ -567,6 +1325,401 @@ mod test {
         assert!(!"im_not_kebab".is_case(Case::Kebab));
     }
 
+    #[test]
+    fn to_case_cow_borrows_when_unchanged() {
+        let s = String::from("already_snake");
+        match s.to_case_cow(Case::Snake) {
+            Cow::Borrowed(b) => assert_eq!(s.as_str().as_ptr(), b.as_ptr()),
+            Cow::Owned(_) => panic!("expected a borrowed string"),
+        }
+        assert!(matches!(
+            "My Title".to_case_cow(Case::Title),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn to_case_cow_borrows_flat_cases() {
+        assert!(matches!("foobar".to_case_cow(Case::Flat), Cow::Borrowed(_)));
+        assert!(matches!(
+            "FOOBAR".to_case_cow(Case::UpperFlat),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            "foobar2".to_case_cow(Case::Flat),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(
+            Cow::<str>::Owned("foobar".to_string()),
+            "fooBar".to_case_cow(Case::Flat)
+        );
+        assert_eq!(
+            Cow::<str>::Owned("FOOBAR".to_string()),
+            "foo_bar".to_case_cow(Case::UpperFlat)
+        );
+    }
+
+    #[test]
+    fn to_case_cow_owns_when_changed() {
+        let cow = "alreadySnake".to_case_cow(Case::Snake);
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!("already_snake", cow);
+    }
+
+    #[test]
+    fn to_case_into_matches_to_case() {
+        let mut buf = String::new();
+        for case in Case::deterministic_cases() {
+            for s in ["myVarName", "XMLHttpRequest", "my-var_name 22", ""] {
+                s.to_case_into(case, &mut buf);
+                assert_eq!(s.to_case(case), buf);
+            }
+        }
+    }
+
+    #[test]
+    fn redelimit_keeps_word_case() {
+        let s = "myCrazy-word-LIST";
+        assert_eq!("myCrazywordLIST", s.redelimit(Case::Kebab, ""));
+        assert_eq!("myCrazy_word_LIST", s.redelimit(Case::Kebab, "_"));
+        assert_eq!("myCrazy word LIST", s.redelimit(Case::Kebab, " "));
+        assert_eq!("my/Crazy-word-LIST", s.redelimit(Case::Camel, "/"));
+        assert_eq!("", "--".redelimit(Case::Kebab, "_"));
+    }
+
+    #[test]
+    fn to_case_multi_matches_to_case() {
+        assert_eq!(
+            vec!["my_var_name", "MyVarName", "my-var-name"],
+            "myVarName".to_case_multi(&[Case::Snake, Case::Pascal, Case::Kebab])
+        );
+        let cases = Case::deterministic_cases();
+        for s in [
+            "XMLHttpRequest2",
+            "__weird--var _name-",
+            "",
+            "MúsicaModerna",
+        ] {
+            let expected: Vec<String> = cases.iter().map(|&case| s.to_case(case)).collect();
+            assert_eq!(expected, s.to_case_multi(&cases));
+        }
+        assert!("myVar".to_case_multi(&[]).is_empty());
+    }
+
+    #[test]
+    fn try_to_case_parses_name() {
+        assert_eq!(Ok("my-var".to_string()), "myVar".try_to_case("kebab"));
+        assert_eq!(
+            Ok("MY_VAR".to_string()),
+            String::from("myVar").try_to_case("Upper Snake")
+        );
+        let err = "myVar".try_to_case("snek").unwrap_err();
+        assert_eq!(None, err.required_feature());
+    }
+
+    #[cfg(not(feature = "random"))]
+    #[test]
+    fn try_to_case_random_requires_feature() {
+        for name in ["random", "pseudo_random"] {
+            let err = "myVar".try_to_case(name).unwrap_err();
+            assert_eq!(Some("random"), err.required_feature());
+        }
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn try_to_case_random() {
+        let random = "myVar".try_to_case("pseudo_random").unwrap();
+        assert_eq!("my var", random.to_lowercase());
+    }
+
+    #[test]
+    fn is_case_with_boundaries() {
+        use Boundary::*;
+        assert!("ABC_123".is_case_with_boundaries(Case::UpperSnake, &[Underscore]));
+        assert!("ABC2D_X".is_case_with_boundaries(Case::UpperSnake, &[Underscore]));
+        assert!(!"ABC_def".is_case_with_boundaries(Case::UpperSnake, &[Underscore]));
+        assert!("my-var".is_case_with_boundaries(Case::Kebab, &[Hyphen]));
+        assert!(!"my_var".is_case_with_boundaries(Case::Kebab, &[Underscore]));
+        assert!(!"myVar".is_case_with_boundaries(Case::Camel, &[]));
+        assert!("myVar".is_case_with_boundaries(Case::Camel, &[LowerUpper]));
+    }
+
+    #[test]
+    fn state_converter_words() {
+        assert_eq!(
+            vec!["my", "Var-name"],
+            "myVar-name".from_case(Case::Camel).words()
+        );
+        assert_eq!(
+            vec!["myVar", "name"],
+            "myVar-name".from_case(Case::Kebab).words()
+        );
+        let bs = [Boundary::Hyphen, Boundary::LowerUpper];
+        assert_eq!(
+            vec!["my", "Var", "name"],
+            "myVar-name".with_boundaries(&bs).words()
+        );
+        let s = String::from("__XMLHttp request_");
+        assert_eq!(
+            vec!["XML", "Http", "request"],
+            s.from_pattern("_ aA AAa").words()
+        );
+        assert!("".from_case(Case::Snake).words().is_empty());
+    }
+
+    #[test]
+    fn summarize_under_and_over_max() {
+        let summarize = |s: &str, max| s.from_case(Case::Snake).summarize(max).to_case(Case::Snake);
+        assert_eq!("a_b_and_3_more", summarize("a_b_c_d_e", 2));
+        assert_eq!("a_b_c_d_and_1_more", summarize("a_b_c_d_e", 4));
+        assert_eq!("a_b_c_d_e", summarize("a_b_c_d_e", 5));
+        assert_eq!("a_b", summarize("a_b", 3));
+        assert_eq!("and_2_more", summarize("a_b", 0));
+        assert_eq!("", summarize("", 0));
+    }
+
+    #[test]
+    fn to_case_report_acronyms() {
+        let report = |s: &str| s.to_case_report_acronyms(Case::Snake);
+        assert_eq!(("io_stream".to_string(), true), report("IOStream"));
+        assert_eq!(("io_stream".to_string(), false), report("ioStream"));
+        assert_eq!(
+            ("xml_http_request".to_string(), true),
+            report("XMLHttpRequest")
+        );
+        assert_eq!(("io".to_string(), false), report("IO"));
+        assert_eq!(("my_var".to_string(), false), report("my_var"));
+        assert_eq!(("".to_string(), false), report(""));
+        let (camel, _) = "IOStream".to_case_report_acronyms(Case::Camel);
+        assert_eq!("IOStream".to_case(Case::Camel), camel);
+    }
+
+    #[test]
+    fn identifiers_equal_ignoring_style() {
+        assert!(identifiers_equal_ignoring_case_style(
+            "getUserName",
+            "GET_USER_NAME"
+        ));
+        assert!(identifiers_equal_ignoring_case_style(
+            "XMLHttpRequest",
+            "xml-http-request"
+        ));
+        assert!(identifiers_equal_ignoring_case_style("", "__"));
+        assert!(!identifiers_equal_ignoring_case_style(
+            "getUserName",
+            "get_user_id"
+        ));
+        assert!(!identifiers_equal_ignoring_case_style(
+            "userName", "username"
+        ));
+    }
+
+    #[test]
+    fn identifier_differences_by_word() {
+        assert_eq!(
+            vec![(Some("Name"), Some("id"))],
+            identifier_differences("getUserName", "get_user_id")
+        );
+        assert_eq!(
+            vec![(Some("user"), Some("username")), (Some("Name"), None)],
+            identifier_differences("userName", "username")
+        );
+        assert!(identifier_differences("Ünïcode Wörds", "ünïcode_wörds").is_empty());
+    }
+
+    #[test]
+    fn cased_display_formats_lazily() {
+        assert_eq!(
+            "foo_bar",
+            format!(
+                "{}",
+                cased_display(format_args!("foo{}", "Bar"), Case::Snake)
+            )
+        );
+        let id = 42;
+        assert_eq!(
+            "USER_ID_42",
+            format!(
+                "{}",
+                cased_display(format_args!("user id {}", id), Case::UpperSnake)
+            )
+        );
+        assert_eq!("", cased_display("", Case::Camel).to_string());
+        assert_eq!(
+            "my-var",
+            cased_display(String::from("MyVar"), Case::Kebab).to_string()
+        );
+    }
+
+    #[test]
+    fn word_count() {
+        assert_eq!(3, "myVarName".word_count(Case::Camel));
+        assert_eq!(1, "__weird--var".word_count(Case::Snake));
+        assert_eq!(2, "__weird--var".word_count(Case::Kebab));
+        assert_eq!(0, "___".word_count(Case::Snake));
+        assert_eq!(3, String::from("XML Http Request").word_count(Case::Title));
+    }
+
+    #[test]
+    fn to_case_len_counts_graphemes() {
+        let (s, len) = "MúsicaModerna".to_case_len(Case::Kebab);
+        assert_eq!("música-moderna", s);
+        assert_eq!(14, len);
+        assert_eq!(15, s.len());
+
+        // "e" with a combining acute accent is one grapheme of three bytes
+        let (s, len) = "cafe\u{301} au lait".to_case_len(Case::Pascal);
+        assert_eq!(10, len);
+        assert_eq!(12, s.len());
+        assert_eq!((String::new(), 0), "".to_case_len(Case::Snake));
+    }
+
+    #[test]
+    fn iter_to_case() {
+        let names = ["userId", "USER_NAME", "created at", "Updated-At"];
+        assert_eq!(
+            vec!["user_id", "user_name", "created_at", "updated_at"],
+            names.iter().to_case(Case::Snake).collect::<Vec<_>>()
+        );
+
+        let owned: Vec<String> = names.iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            names
+                .iter()
+                .map(|s| s.to_case(Case::Pascal))
+                .collect::<Vec<_>>(),
+            owned.into_iter().to_case(Case::Pascal).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn title_with_stopwords() {
+        let stop_words = ["the", "of", "to"];
+        assert_eq!(
+            "The Return of the King",
+            "THE_RETURN_OF_THE_KING".to_title_with_stopwords(&stop_words)
+        );
+        assert_eq!("Where To", "whereTo".to_title_with_stopwords(&stop_words));
+        assert_eq!(
+            "Tale Of Two Cities",
+            "tale of two cities".to_title_with_stopwords(&[])
+        );
+    }
+
+    #[test]
+    fn option_to_case() {
+        assert_eq!(
+            Some("foo_bar".to_string()),
+            Some("fooBar").to_case(Case::Snake)
+        );
+        assert_eq!(None, None::<&str>.to_case(Case::Snake));
+        assert_eq!(
+            Some("FOO-BAR".to_string()),
+            Some(String::from("foo bar")).to_case(Case::Cobol)
+        );
+    }
+
+    #[test]
+    fn without_digit_boundaries() {
+        let s = "TransformationsIn3D";
+        assert_eq!(
+            s.from_case(Case::Camel)
+                .without_boundaries(&Boundary::digits())
+                .to_case(Case::Snake),
+            s.from_case(Case::Camel)
+                .without_digit_boundaries()
+                .to_case(Case::Snake)
+        );
+        assert_eq!(
+            "transformations_in_3d",
+            s.from_case(Case::Camel)
+                .without_boundaries(&Boundary::digit_letter())
+                .to_case(Case::Snake)
+        );
+        assert_eq!(
+            "release_v2_2024",
+            "releaseV2 2024"
+                .with_boundaries(&Boundary::defaults())
+                .without_digit_boundaries()
+                .to_case(Case::Snake)
+        );
+    }
+
+    #[test]
+    fn field_names_macro() {
+        assert_eq!(
+            vec!["first_name", "last_name"],
+            field_names_in_case!(Case::Snake; first_name, lastName,)
+        );
+        assert_eq!(
+            vec!["FieldOne"],
+            field_names_in_case!(Case::Pascal; field_one)
+        );
+        assert!(field_names_in_case!(Case::Kebab;).is_empty());
+    }
+
+    #[test]
+    fn string_is_upper_snake_with_digits() {
+        assert!("UPPER_CASE_WITH_DIGIT1".is_case(Case::UpperSnake));
+        assert!("V2_API_3D".is_case(Case::ScreamingSnake));
+        assert!(!"UPPER_CASE_with_DIGIT1".is_case(Case::UpperSnake));
+    }
+
+    #[test]
+    fn string_is_kebab_with_digits() {
+        assert!("im-kebab-case-2".is_case(Case::Kebab));
+        assert!("utf8-decoder".is_case(Case::Kebab));
+        assert!(!"utf8_decoder".is_case(Case::Kebab));
+    }
+
+    #[test]
+    fn digit_only_is_any_lowercase_case() {
+        for s in ["123", "0", "2024"] {
+            assert!(s.is_case(Case::Snake), "{}", s);
+            assert!(s.is_case(Case::Kebab), "{}", s);
+            assert!(s.is_case(Case::Camel), "{}", s);
+        }
+        assert!("1_2".is_case(Case::Snake));
+        assert!(!"1_2".is_case(Case::Kebab));
+        assert!(!"1_2".is_case(Case::Camel));
+    }
+
+    #[test]
+    fn single_letter_case() {
+        for case in [Case::Snake, Case::Kebab, Case::Camel] {
+            assert!("a".is_case(case), "{:?}", case);
+            assert!(!"A".is_case(case), "{:?}", case);
+        }
+        assert!("A".is_case(Case::Pascal));
+        assert!("A".is_case(Case::UpperSnake));
+    }
+
+    #[test]
+    fn symbols_belong_to_words() {
+        // symbols that aren't delimiters are kept inside words
+        assert!("my_var!".is_case(Case::Snake));
+        assert!("a$b".is_case(Case::Snake));
+        assert!("a$b".is_case(Case::Kebab));
+        assert!("a$b".is_case(Case::Camel));
+        assert!("my.var".is_case(Case::Snake));
+        assert!(!"my_var!".is_case(Case::Kebab));
+        assert!(!"my$Var".is_case(Case::Snake));
+        assert!(!"my$Var".is_case(Case::Camel));
+        // leading and trailing delimiters are removed when converting
+        assert!(!"_".is_case(Case::Snake));
+        assert!(!"__init".is_case(Case::Snake));
+        assert!(!"-my-var-".is_case(Case::Kebab));
+    }
+
+    #[test]
+    fn string_is_not_other_delimited_case() {
+        assert!(!"im_NOTsnake".is_case(Case::Snake));
+        assert!(!"im-kebab-case".is_case(Case::Snake));
+        assert!(!"im_snake_case".is_case(Case::Kebab));
+        assert!(!"my var".is_case(Case::Flat));
+    }
+
     #[test]
     fn remove_boundaries() {
         assert_eq!(
@@ -578,6 +1731,16 @@ mod test {
         );
     }
 
+    #[test]
+    fn with_digit_run_boundaries() {
+        assert_eq!(
+            "v-2-api-3",
+            "v2api3"
+                .with_boundaries(&Boundary::digit_runs())
+                .to_case(Case::Kebab)
+        );
+    }
+
     #[test]
     fn with_boundaries() {
         assert_eq!(
@@ -588,6 +1751,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn from_pattern() {
+        assert_eq!(
+            "7empest By Tool",
+            "7empest byTool".from_pattern("aA ").to_case(Case::Title)
+        );
+        assert_eq!(
+            "7empest_by_tool",
+            String::from("7empest byTool")
+                .from_pattern("aA ")
+                .to_case(Case::Snake)
+        );
+    }
+
     #[cfg(feature = "random")]
     #[test]
     fn random_case_boundaries() {
@@ -615,20 +1792,39 @@ mod test {
     use std::collections::HashSet;
     use std::iter::FromIterator;
 
+    #[test]
+    fn detect_all_matches_possible_cases() {
+        for s in [
+            "asef",
+            "asefCase",
+            "AsefCase",
+            "asef_case",
+            "ASEF-CASE",
+            "Asef Case",
+            "my/var.name",
+            "v2_model",
+            "",
+        ] {
+            assert_eq!(possible_cases(s), Case::detect_all(s), "{}", s);
+        }
+    }
+
     #[test]
     fn detect_many_cases() {
-        let lower_cases_vec = possible_cases(&"asef");
-        let lower_cases_set = HashSet::from_iter(lower_cases_vec.into_iter());
+        let lower_cases_vec = possible_cases("asef");
+        let lower_cases_set = HashSet::from_iter(lower_cases_vec);
         let mut actual = HashSet::new();
         actual.insert(Case::Lower);
         actual.insert(Case::Camel);
         actual.insert(Case::Snake);
         actual.insert(Case::Kebab);
+        actual.insert(Case::Path);
+        actual.insert(Case::Dot);
         actual.insert(Case::Flat);
         assert_eq!(lower_cases_set, actual);
 
-        let lower_cases_vec = possible_cases(&"asefCase");
-        let lower_cases_set = HashSet::from_iter(lower_cases_vec.into_iter());
+        let lower_cases_vec = possible_cases("asefCase");
+        let lower_cases_set = HashSet::from_iter(lower_cases_vec);
         let mut actual = HashSet::new();
         actual.insert(Case::Camel);
         assert_eq!(lower_cases_set, actual);
@@ -658,4 +1854,150 @@ mod test {
         let s = "ПЕРСПЕКТИВА24".to_string();
         let _n = s.to_case(Case::Title);
     }
+
+    #[test]
+    fn turkish_locale() {
+        assert_eq!(
+            "istanbul",
+            "İSTANBUL".to_case_with_locale(Case::Lower, Locale::Turkish)
+        );
+        assert_eq!(
+            "ırmak",
+            "IRMAK".to_case_with_locale(Case::Lower, Locale::Turkish)
+        );
+        assert_eq!(
+            "İZMİR",
+            "izmir".to_case_with_locale(Case::Upper, Locale::Turkish)
+        );
+        assert_eq!(
+            "IşıkİçiDoğru",
+            "ışık içi doğru".to_case_with_locale(Case::Pascal, Locale::Turkish)
+        );
+    }
+
+    #[test]
+    fn greek_final_sigma_default_locale() {
+        assert_eq!(
+            "ὀδυσσεύς",
+            "ὈΔΥΣΣΕΎΣ".to_case_with_locale(Case::Lower, Locale::Default)
+        );
+        assert_eq!("ὀδυσσεύς", "ὈΔΥΣΣΕΎΣ".to_case(Case::Lower));
+    }
+
+    #[test]
+    fn check_from_case_for_wrong_case() {
+        let warning = "my-kebab".check_from_case(Case::Snake).unwrap();
+        assert!(warning.contains("one word from Snake case"));
+        assert!(warning.contains("looks like Kebab case"));
+        assert_eq!(None, "my-kebab".check_from_case(Case::Kebab));
+        assert_eq!(None, "word".check_from_case(Case::Snake));
+    }
+
+    #[test]
+    fn from_case_checked_converts_right_case() {
+        assert_eq!(
+            "my_kebab",
+            "my-kebab"
+                .from_case_checked(Case::Kebab)
+                .to_case(Case::Snake)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "one word from Snake case")]
+    fn from_case_checked_panics_on_wrong_case() {
+        "my-kebab".from_case_checked(Case::Snake);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn from_case_checked_ignores_wrong_case_in_release() {
+        assert_eq!(
+            "my-kebab",
+            "my-kebab"
+                .from_case_checked(Case::Snake)
+                .to_case(Case::Snake)
+        );
+    }
+
+    fn dot_case() -> OwnedCase {
+        Converter::new()
+            .set_boundaries(&[Boundary::from_delim(".")])
+            .set_pattern(Pattern::Lowercase)
+            .set_delim(".")
+            .into_case()
+    }
+
+    #[test]
+    fn owned_case_membership() {
+        let dot_case = dot_case();
+        assert!(dot_case.is_case("my.new.case"));
+        assert!(dot_case.is_case("single"));
+        assert!(!dot_case.is_case("myNewCase"));
+        assert!(!dot_case.is_case("my.New.case"));
+        assert!(!dot_case.is_case("my_new_case"));
+    }
+
+    #[test]
+    fn owned_case_membership_matches_built_in_cases() {
+        for case in Case::deterministic_cases() {
+            let owned = OwnedCase::from(case);
+            for s in [
+                "my_var_name",
+                "MyVarName",
+                "my-var-name",
+                "My Var Name",
+                "myVarName",
+            ] {
+                assert_eq!(s.is_case(case), owned.is_case(s), "{} {:?}", s, case);
+            }
+        }
+    }
+
+    #[test]
+    fn char_to_case() {
+        assert_eq!("X", 'x'.to_case(Case::Upper));
+        assert_eq!("Ä", 'ä'.to_case(Case::Upper));
+        assert_eq!("ä", 'Ä'.to_case(Case::Snake));
+        assert_eq!("", '-'.to_case(Case::Kebab));
+        assert_eq!("", ' '.to_case(Case::Camel));
+    }
+
+    #[test]
+    fn keep_version_suffixes() {
+        let snake = |s: &str| {
+            s.from_case(Case::Camel)
+                .keep_version_suffixes()
+                .to_case(Case::Snake)
+        };
+        assert_eq!("api_v2", snake("apiV2"));
+        assert_eq!("utf8_decoder", snake("utf8Decoder"));
+        assert_eq!("http2_client_v3", snake("http2ClientV3"));
+        assert_eq!(
+            "api_v_2",
+            "apiV2".from_case(Case::Camel).to_case(Case::Snake)
+        );
+    }
+
+    #[test]
+    fn state_converter_accessors() {
+        let conv = "myVar2".from_case(Case::Camel);
+        assert_eq!(Case::Camel.boundaries(), conv.boundaries());
+        let conv = conv.without_boundaries(&Boundary::digits());
+        assert!(conv
+            .boundaries()
+            .iter()
+            .all(|b| !Boundary::digits().contains(b)));
+        assert_eq!(
+            &[Boundary::LowerUpper, Boundary::Acronym],
+            conv.boundaries()
+        );
+        assert_eq!(None, conv.pattern());
+        assert_eq!("", conv.delim());
+        assert_eq!(
+            Boundary::defaults(),
+            "x".with_boundaries(&Boundary::defaults()).boundaries()
+        );
+    }
 }
@@ -1,7 +1,13 @@
-use std::iter;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::iter;
 
 #[cfg(feature = "random")]
 use rand::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[cfg(feature = "diacritics")]
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 enum WordCase {
@@ -13,16 +19,19 @@ enum WordCase {
 
 impl WordCase {
     fn mutate(&self, word: &str) -> String {
+        self.mutate_in(word, CaseMapping::default())
+    }
+
+    fn mutate_in(&self, word: &str, mapping: CaseMapping) -> String {
         use WordCase::*;
         match self {
-            Lower => word.to_lowercase(),
-            Upper => word.to_uppercase(),
+            Lower => mapping.lowercase(word),
+            Upper => mapping.uppercase(word),
             Capital => {
                 let mut chars = word.chars();
                 if let Some(c) = chars.next() {
-                    c.to_uppercase()
-                        .chain(chars.as_str().to_lowercase().chars())
-                        .collect()
+                    let first = mapping.uppercase(c.encode_utf8(&mut [0; 4]));
+                    first + &mapping.lowercase(chars.as_str())
                 } else {
                     String::new()
                 }
@@ -30,9 +39,8 @@ impl WordCase {
             Toggle => {
                 let mut chars = word.chars();
                 if let Some(c) = chars.next() {
-                    c.to_lowercase()
-                        .chain(chars.as_str().to_uppercase().chars())
-                        .collect()
+                    let first = mapping.lowercase(c.encode_utf8(&mut [0; 4]));
+                    first + &mapping.uppercase(chars.as_str())
                 } else {
                     String::new()
                 }
@@ -41,6 +49,69 @@ impl WordCase {
     }
 }
 
+/// A locale that changes how some letters map between lowercase and uppercase.  The
+/// default locale uses the Unicode default case mappings from the standard library.
+///
+/// Set it on a converter with [`Converter::set_locale`](crate::Converter::set_locale).  It
+/// applies to the lowercase, uppercase and capitalizing patterns.
+/// ```
+/// use convert_case::{Case, Converter, Locale};
+///
+/// let conv = Converter::new()
+///     .to_case(Case::Upper)
+///     .set_locale(Locale::Turkish);
+/// assert_eq!("İSTANBUL IŞIK", conv.convert("istanbul ışık"));
+/// ```
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum Locale {
+    /// The Unicode default case mappings.
+    #[default]
+    Default,
+
+    /// Turkish and Azerbaijani, where dotted `i` uppercases to `İ` and dotless `ı`
+    /// uppercases to `I`, and the reverse when lowercasing.
+    Turkish,
+}
+
+impl Locale {
+    fn lowercase(self, s: &str) -> String {
+        match self {
+            Locale::Default => s.to_lowercase(),
+            Locale::Turkish => s.replace('I', "ı").replace('İ', "i").to_lowercase(),
+        }
+    }
+
+    fn uppercase(self, s: &str) -> String {
+        match self {
+            Locale::Default => s.to_uppercase(),
+            Locale::Turkish => s.replace('i', "İ").to_uppercase(),
+        }
+    }
+}
+
+/// How letters are mapped between lowercase and uppercase when a pattern is applied.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub(crate) struct CaseMapping {
+    pub(crate) locale: Locale,
+
+    // Uppercases `ß` to `ẞ` instead of `SS`.
+    pub(crate) capital_sharp_s: bool,
+}
+
+impl CaseMapping {
+    fn lowercase(self, s: &str) -> String {
+        self.locale.lowercase(s)
+    }
+
+    fn uppercase(self, s: &str) -> String {
+        if self.capital_sharp_s && s.contains('ß') {
+            self.locale.uppercase(&s.replace('ß', "ẞ"))
+        } else {
+            self.locale.uppercase(s)
+        }
+    }
+}
+
 /// A pattern is how a set of words is mutated before joining with
 /// a delimeter.
 ///
@@ -129,6 +200,20 @@ pub enum Pattern {
     /// ```
     Toggle,
 
+    /// Title patterns with stop words capitalize each word like [`Capital`](Pattern::Capital),
+    /// except for the given stop words, which are lowercased unless they are the first or
+    /// last word.  Stop words are matched ignoring case.  Usually built with
+    /// [`Pattern::title_case`].
+    /// ```
+    /// use convert_case::Pattern;
+    /// let title = Pattern::TitleWithStopWords(&["of", "the"]);
+    /// assert_eq!(
+    ///     vec!["The", "Lord", "of", "the", "Rings"],
+    ///     title.mutate(&["the", "lord", "OF", "the", "rings"])
+    /// );
+    /// ```
+    TitleWithStopWords(&'static [&'static str]),
+
     /// Random patterns will lowercase or uppercase each letter
     /// uniformly randomly.  This uses the `rand` crate and is only available with the "random"
     /// feature.  This example will not pass the assertion due to randomness, but it used as an 
@@ -158,7 +243,7 @@ pub enum Pattern {
     /// # #[cfg(any(doc, feature = "random"))]
     /// assert_eq!(
     ///     vec!["cAsE", "cONveRSioN", "lIBrAry"],
-    ///     Pattern::Random.mutate(&["Case", "CONVERSION", "library"]),
+    ///     Pattern::PseudoRandom.mutate(&["Case", "CONVERSION", "library"]),
     /// );
     /// ```
     #[cfg(any(doc, feature = "random"))]
@@ -167,6 +252,21 @@ pub enum Pattern {
 }
 
 impl Pattern {
+    /// Creates a title case pattern that lowercases the given stop words, except when they are
+    /// the first or last word.  Use it with a space delimiter for AP or Chicago style titles.
+    /// ```
+    /// use convert_case::{Converter, Pattern};
+    ///
+    /// let title = Converter::new()
+    ///     .set_pattern(Pattern::title_case(&["a", "an", "the", "to", "for", "of", "and"]))
+    ///     .set_delim(" ");
+    /// assert_eq!("The Lord of the Rings", title.convert("the lord of the rings"));
+    /// assert_eq!("Something to Look For", title.convert("something to look for"));
+    /// ```
+    pub const fn title_case(stop_words: &'static [&'static str]) -> Self {
+        Pattern::TitleWithStopWords(stop_words)
+    }
+
     /// Generates a vector of new `String`s in the right pattern given
     /// the input strings.
     /// ```
@@ -178,23 +278,28 @@ impl Pattern {
     /// )
     /// ```
     pub fn mutate(&self, words: &[&str]) -> Vec<String> {
+        self.mutate_in(words, CaseMapping::default())
+    }
+
+    /// Mutates words like [`mutate`](Pattern::mutate), using the given case mappings.
+    pub(crate) fn mutate_in(&self, words: &[&str], mapping: CaseMapping) -> Vec<String> {
         use Pattern::*;
         match self {
             Lowercase => words
                 .iter()
-                .map(|word| WordCase::Lower.mutate(word))
+                .map(|word| WordCase::Lower.mutate_in(word, mapping))
                 .collect(),
             Uppercase => words
                 .iter()
-                .map(|word| WordCase::Upper.mutate(word))
+                .map(|word| WordCase::Upper.mutate_in(word, mapping))
                 .collect(),
             Capital => words
                 .iter()
-                .map(|word| WordCase::Capital.mutate(word))
+                .map(|word| WordCase::Capital.mutate_in(word, mapping))
                 .collect(),
             Toggle => words
                 .iter()
-                .map(|word| WordCase::Toggle.mutate(word))
+                .map(|word| WordCase::Toggle.mutate_in(word, mapping))
                 .collect(),
             Sentence => {
                 let word_cases =
@@ -202,7 +307,7 @@ impl Pattern {
                 words
                     .iter()
                     .zip(word_cases)
-                    .map(|(word, word_case)| word_case.mutate(word))
+                    .map(|(word, word_case)| word_case.mutate_in(word, mapping))
                     .collect()
             }
             Camel => {
@@ -211,14 +316,32 @@ impl Pattern {
                 words
                     .iter()
                     .zip(word_cases)
-                    .map(|(word, word_case)| word_case.mutate(word))
+                    .map(|(word, word_case)| word_case.mutate_in(word, mapping))
                     .collect()
             }
             Alternating => alternating(words),
+            TitleWithStopWords(stop_words) => title_with_stop_words(words, stop_words, mapping),
             #[cfg(feature = "random")]
-            Random => randomize(words),
+            Random => randomize(words, &mut rand::thread_rng(), mapping),
             #[cfg(feature = "random")]
-            PseudoRandom => pseudo_randomize(words),
+            PseudoRandom => pseudo_randomize(words, &mut rand::thread_rng(), mapping),
+        }
+    }
+
+    /// Mutates words like [`mutate_in`](Pattern::mutate_in), but seeds the random number
+    /// generator used by `Random` and `PseudoRandom` so the output is reproducible.
+    #[cfg(feature = "random")]
+    pub(crate) fn mutate_seeded(
+        &self,
+        words: &[&str],
+        seed: u64,
+        mapping: CaseMapping,
+    ) -> Vec<String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        match self {
+            Pattern::Random => randomize(words, &mut rng, mapping),
+            Pattern::PseudoRandom => pseudo_randomize(words, &mut rng, mapping),
+            _ => self.mutate_in(words, mapping),
         }
     }
 }
@@ -247,19 +370,111 @@ fn alternating(words: &[&str]) -> Vec<String> {
         .collect()
 }
 
-/// Randomly picks whether to be upper case or lower case
+/// Capitalizes each word except stop words that aren't the first or last word.
+pub(crate) fn title_with_stop_words(
+    words: &[&str],
+    stop_words: &[&str],
+    mapping: CaseMapping,
+) -> Vec<String> {
+    let last = words.len().saturating_sub(1);
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            let lower = mapping.lowercase(word);
+            let is_stop_word = stop_words
+                .iter()
+                .any(|stop| mapping.lowercase(stop) == lower);
+            if is_stop_word && i != 0 && i != last {
+                lower
+            } else {
+                WordCase::Capital.mutate_in(word, mapping)
+            }
+        })
+        .collect()
+}
+
+/// Like `Pattern::Camel`, but only lowercases the first grapheme of the first word, leaving
+/// the rest of that word as it was.
+pub(crate) fn camel_lower_first_letter_only(words: &[&str], mapping: CaseMapping) -> Vec<String> {
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            if i > 0 {
+                return WordCase::Capital.mutate_in(word, mapping);
+            }
+            match word.graphemes(true).next() {
+                Some(first) => mapping.lowercase(first) + &word[first.len()..],
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Removes every grapheme that doesn't start with a letter or digit.
+pub(crate) fn strip_symbols(word: &str) -> String {
+    word.graphemes(true)
+        .filter(|g| g.chars().next().is_some_and(char::is_alphanumeric))
+        .collect()
+}
+
+/// Whether the word begins with an uppercase letter and the rest is not uppercase, the
+/// way `Pattern::Capital` leaves a word.
+pub(crate) fn is_capitalized(word: &str) -> bool {
+    word.chars().next().is_some_and(char::is_uppercase) && WordCase::Capital.mutate(word) == word
+}
+
+/// Uppercases the first grapheme of the first word and of every word after one that ends a
+/// sentence with a period, leaving the rest of each word as it was.
+pub(crate) fn capitalize_sentences(words: &mut [String]) {
+    let mut sentence_start = true;
+    for word in words.iter_mut() {
+        if sentence_start {
+            if let Some(first) = word.graphemes(true).next() {
+                *word = first.to_uppercase() + &word[first.len()..];
+            }
+        }
+        if !word.is_empty() {
+            sentence_start = word.ends_with('.');
+        }
+    }
+}
+
+/// Uppercases any letter that directly follows an ASCII digit.
+pub(crate) fn capitalize_after_digits(word: &str) -> String {
+    let mut capitalized = String::with_capacity(word.len());
+    let mut after_digit = false;
+    for c in word.chars() {
+        if after_digit && c.is_lowercase() {
+            capitalized.extend(c.to_uppercase());
+        } else {
+            capitalized.push(c);
+        }
+        after_digit = c.is_ascii_digit();
+    }
+    capitalized
+}
+
+/// Decomposes each character and removes any combining marks, like accents.
+#[cfg(feature = "diacritics")]
+pub(crate) fn strip_diacritics(word: &str) -> String {
+    word.nfd().filter(|&c| !is_combining_mark(c)).collect()
+}
+
+/// Randomly picks whether to be upper case or lower case.  Graphemes are cased as a whole
+/// so combining characters stay attached to their base character.
 #[cfg(feature = "random")]
-fn randomize(words: &[&str]) -> Vec<String> {
-    let mut rng = rand::thread_rng();
+fn randomize<R: Rng>(words: &[&str], rng: &mut R, mapping: CaseMapping) -> Vec<String> {
     words
         .iter()
         .map(|word| {
-            word.chars()
-                .map(|letter| {
+            word.graphemes(true)
+                .map(|grapheme| {
                     if rng.gen::<f32>() > 0.5 {
-                        letter.to_uppercase().to_string()
+                        mapping.uppercase(grapheme)
                     } else {
-                        letter.to_lowercase().to_string()
+                        mapping.lowercase(grapheme)
                     }
                 })
                 .collect()
@@ -268,36 +483,34 @@ fn randomize(words: &[&str]) -> Vec<String> {
 }
 
 /// Randomly selects patterns: [upper, lower] or [lower, upper]
-/// for a more random feeling pattern.
+/// for a more random feeling pattern.  Like `randomize`, this cases whole graphemes.
 #[cfg(feature = "random")]
-fn pseudo_randomize(words: &[&str]) -> Vec<String> {
-    let mut rng = rand::thread_rng();
-
+fn pseudo_randomize<R: Rng>(words: &[&str], rng: &mut R, mapping: CaseMapping) -> Vec<String> {
     // Keeps track of when to alternate
     let mut alt: Option<bool> = None;
     words
         .iter()
         .map(|word| {
-            word.chars()
-                .map(|letter| {
+            word.graphemes(true)
+                .map(|grapheme| {
                     match alt {
                         // No existing pattern, start one
                         None => {
                             if rng.gen::<f32>() > 0.5 {
-                                alt = Some(false); // Make the next char lower
-                                letter.to_uppercase().to_string()
+                                alt = Some(false); // Make the next grapheme lower
+                                mapping.uppercase(grapheme)
                             } else {
-                                alt = Some(true); // Make the next char upper
-                                letter.to_lowercase().to_string()
+                                alt = Some(true); // Make the next grapheme upper
+                                mapping.lowercase(grapheme)
                             }
                         }
                         // Existing pattern, do what it says
                         Some(upper) => {
                             alt = None;
                             if upper {
-                                letter.to_uppercase().to_string()
+                                mapping.uppercase(grapheme)
                             } else {
-                                letter.to_lowercase().to_string()
+                                mapping.lowercase(grapheme)
                             }
                         }
                     }
@@ -315,8 +528,9 @@ mod test {
     #[test]
     fn pseudo_no_triples() {
         let words = vec!["abcdefg", "hijklmnop", "qrstuv", "wxyz"];
+        let mapping = CaseMapping::default();
         for _ in 0..5 {
-            let new = pseudo_randomize(&words).join("");
+            let new = pseudo_randomize(&words, &mut thread_rng(), mapping).join("");
             let mut iter = new
                 .chars()
                 .zip(new.chars().skip(1))
@@ -330,19 +544,136 @@ mod test {
         }
     }
 
+    #[cfg(feature = "random")]
+    #[test]
+    fn pseudo_random_pattern_mutates() {
+        let words = ["Case", "CONVERSION", "library"];
+        for _ in 0..5 {
+            let mutated = Pattern::PseudoRandom.mutate(&words);
+            assert_eq!(words.len(), mutated.len());
+            for (word, new) in words.iter().zip(&mutated) {
+                assert_eq!(word.to_lowercase(), new.to_lowercase());
+            }
+        }
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn randoms_keep_graphemes() {
+        // "e" followed by a combining acute accent is a single grapheme
+        let words = vec!["cafe\u{301}", "nai\u{308}ve", "e\u{301}e\u{301}e\u{301}"];
+        let mapping = CaseMapping::default();
+        for _ in 0..5 {
+            for transformed in [
+                randomize(&words, &mut thread_rng(), mapping),
+                pseudo_randomize(&words, &mut thread_rng(), mapping),
+            ] {
+                for (word, new) in words.iter().zip(&transformed) {
+                    let old: Vec<_> = word.graphemes(true).map(str::to_lowercase).collect();
+                    let new: Vec<_> = new.graphemes(true).map(str::to_lowercase).collect();
+                    assert_eq!(old, new);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn seeded_randoms_repeat() {
+        let words = vec!["abcdefg", "hijklmnop", "qrstuv", "wxyz"];
+        let mapping = CaseMapping::default();
+        for pattern in [Pattern::Random, Pattern::PseudoRandom] {
+            let seeded = |seed| pattern.mutate_seeded(&words, seed, mapping);
+            assert_eq!(seeded(7), seeded(7));
+            assert_ne!(seeded(7), seeded(8));
+        }
+        assert_eq!(
+            Pattern::Lowercase.mutate(&words),
+            Pattern::Lowercase.mutate_seeded(&words, 7, mapping)
+        );
+    }
+
     #[cfg(feature = "random")]
     #[test]
     fn randoms_are_random() {
         let words = vec!["abcdefg", "hijklmnop", "qrstuv", "wxyz"];
+        let mapping = CaseMapping::default();
 
         for _ in 0..5 {
-            let transformed = pseudo_randomize(&words);
+            let transformed = pseudo_randomize(&words, &mut thread_rng(), mapping);
             assert_ne!(words, transformed);
-            let transformed = randomize(&words);
+            let transformed = randomize(&words, &mut thread_rng(), mapping);
             assert_ne!(words, transformed);
         }
     }
 
+    #[test]
+    fn title_stop_words() {
+        let stop_words = ["a", "of", "THE"];
+        let mapping = CaseMapping::default();
+        assert_eq!(
+            vec!["Of", "Mice", "and", "a", "Man", "Of"],
+            title_with_stop_words(
+                &["of", "mice", "and", "A", "man", "of"],
+                &["and", "a", "of"],
+                mapping
+            )
+        );
+        assert_eq!(
+            vec!["The"],
+            title_with_stop_words(&["the"], &stop_words, mapping)
+        );
+        assert!(title_with_stop_words(&[], &stop_words, mapping).is_empty());
+    }
+
+    #[test]
+    fn camel_lower_first_letter() {
+        let mapping = CaseMapping::default();
+        let lower_first = |words: &[&str]| camel_lower_first_letter_only(words, mapping);
+        assert_eq!(vec!["hTTP", "Server"], lower_first(&["HTTP", "SERVER"]));
+        assert_eq!(vec!["éCOLE"], lower_first(&["ÉCOLE"]));
+        assert_eq!(vec!["", "Word"], lower_first(&["", "word"]));
+        assert!(lower_first(&[]).is_empty());
+    }
+
+    #[test]
+    fn strips_symbols() {
+        assert_eq!("10000", strip_symbols("10,000"));
+        assert_eq!("cafe\u{301}", strip_symbols("¡cafe\u{301}!"));
+        assert_eq!("", strip_symbols("&&"));
+    }
+
+    #[test]
+    fn capitalized_words() {
+        assert!(is_capitalized("Word"));
+        assert!(is_capitalized("W"));
+        assert!(!is_capitalized("WORD"));
+        assert!(!is_capitalized("word"));
+        assert!(!is_capitalized("2d"));
+        assert!(!is_capitalized(""));
+    }
+
+    #[test]
+    fn capitalizes_sentences() {
+        let mut words: Vec<String> = ["one", "two.", "three", "iPhone.", "", "ünder", "x"]
+            .iter()
+            .map(|w| w.to_string())
+            .collect();
+        capitalize_sentences(&mut words);
+        assert_eq!(
+            vec!["One", "two.", "Three", "iPhone.", "", "Ünder", "x"],
+            words
+        );
+    }
+
+    #[test]
+    fn capitalizes_after_digits() {
+        assert_eq!("User2Name", capitalize_after_digits("User2name"));
+        assert_eq!("v8A9Bc", capitalize_after_digits("v8a9bc"));
+        assert_eq!("22", capitalize_after_digits("22"));
+        assert_eq!("", capitalize_after_digits(""));
+    }
+
     #[test]
     fn mutate_empty_strings() {
         for wcase in [
@@ -351,7 +682,7 @@ mod test {
             WordCase::Capital,
             WordCase::Toggle,
         ] {
-            assert_eq!(String::new(), wcase.mutate(&String::new()))
+            assert_eq!(String::new(), wcase.mutate(""))
         }
     }
 }
@@ -1,7 +1,14 @@
 #[cfg(test)]
 use strum::EnumIter;
 
-use unicode_segmentation::{UnicodeSegmentation}; //, GraphemeCursor};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
 
 /// A boundary defines how a string is split into words.  Some boundaries, `Hyphen`, `Underscore`,
 /// and `Space`, consume the character they split on, whereas the other boundaries
@@ -31,8 +38,24 @@ use unicode_segmentation::{UnicodeSegmentation}; //, GraphemeCursor};
 ///     .to_case(Case::Title);
 /// assert_eq!("7empest By Tool", conv.convert("7empest byTool"));
 /// ```
+///
+/// Boundaries that aren't covered by the variants above, like splitting on a `.` or `::`, can
+/// be written with the [`Custom`](Boundary::Custom) variant.  The most common use,
+/// splitting on an arbitrary delimiter, is provided by [`Boundary::from_delim`].
+///
+/// `Boundary` is `Copy`, and will stay that way.  Lists of boundaries are passed around by
+/// slice and copied into each `Converter`, so a `Custom` boundary only holds a `fn` pointer
+/// and `&'static str` data.  Boundaries that need to own data or capture state should be
+/// kept by the `Converter` instead of by this enum.
+/// ```
+/// use convert_case::Boundary;
+///
+/// let slash = Boundary::from_delim("/");
+/// let copy = slash;
+/// assert_eq!(slash, copy);
+/// ```
 #[cfg_attr(test, derive(EnumIter))]
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum Boundary {
     /// Splits on `-`, consuming the character on segmentation.
     /// ```
@@ -64,6 +87,28 @@ pub enum Boundary {
     /// ```
     Space,
 
+    /// Splits on a tab, consuming the character on segmentation.  This is not included in the
+    /// [defaults](Boundary::defaults).
+    /// ```
+    /// use convert_case::Boundary;
+    /// assert_eq!(
+    ///     vec![Boundary::Tab],
+    ///     Boundary::list_from("\t")
+    /// );
+    /// ```
+    Tab,
+
+    /// Splits on a line feed, or a carriage return followed by a line feed, consuming it on
+    /// segmentation.  This is not included in the [defaults](Boundary::defaults).
+    /// ```
+    /// use convert_case::Boundary;
+    /// assert_eq!(
+    ///     vec![Boundary::Newline],
+    ///     Boundary::list_from("\r\n")
+    /// );
+    /// ```
+    Newline,
+
     /// Splits where an uppercase letter is followed by a lowercase letter.  This is seldom used,
     /// and is not included in the [defaults](Boundary::defaults).
     /// ```
@@ -136,6 +181,89 @@ pub enum Boundary {
     /// );
     /// ```
     Acronym,
+
+    /// A boundary defined by a function.  `condition` is given the remainder of the string
+    /// starting at the current grapheme and the optional `arg`.  When it returns `true`, the
+    /// string is split `start` graphemes ahead of the current grapheme, and `len` graphemes
+    /// are consumed from that point.
+    ///
+    /// A boundary like `LowerUpper` would be written with `start: 1` and `len: 0`, and a
+    /// delimiter like `Hyphen` with `start: 0` and `len: 1`.
+    ///
+    /// A custom boundary with an `arg` is identified by it: two custom boundaries with the same
+    /// `arg`, `start` and `len` are equal, even if their `condition` functions are different
+    /// pointers.  Function pointers aren't guaranteed to be unique, so this keeps a boundary
+    /// made with [`Boundary::from_delim`] equal to another one made the same way.  Custom
+    /// boundaries without an `arg` are also compared by `condition`.
+    /// ```
+    /// use convert_case::{Boundary, Case, Converter};
+    ///
+    /// let dot_before_digit = Boundary::Custom {
+    ///     condition: |s, _| {
+    ///         let mut chars = s.chars();
+    ///         chars.next() == Some('.') && chars.next().is_some_and(|c| c.is_ascii_digit())
+    ///     },
+    ///     arg: None,
+    ///     start: 0,
+    ///     len: 1,
+    /// };
+    /// let conv = Converter::new()
+    ///     .set_boundaries(&[dot_before_digit])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("version_2_final.txt", conv.convert("version.2_final.txt"));
+    /// ```
+    #[cfg_attr(test, strum(disabled))]
+    Custom {
+        condition: fn(&str, Option<&'static str>) -> bool,
+        arg: Option<&'static str>,
+        start: usize,
+        len: usize,
+    },
+}
+
+impl PartialEq for Boundary {
+    fn eq(&self, other: &Self) -> bool {
+        use Boundary::*;
+        match (self, other) {
+            (
+                Custom {
+                    condition: c1,
+                    arg: a1,
+                    start: s1,
+                    len: l1,
+                },
+                Custom {
+                    condition: c2,
+                    arg: a2,
+                    start: s2,
+                    len: l2,
+                },
+            ) => {
+                a1 == a2
+                    && s1 == s2
+                    && l1 == l2
+                    && (a1.is_some() || core::ptr::fn_addr_eq(*c1, *c2))
+            }
+            (a, b) => core::mem::discriminant(a) == core::mem::discriminant(b),
+        }
+    }
+}
+
+impl Eq for Boundary {}
+
+impl Hash for Boundary {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        // `condition` is left out, since boundaries with an `arg` are equal without it
+        if let Boundary::Custom {
+            arg, start, len, ..
+        } = self
+        {
+            arg.hash(state);
+            start.hash(state);
+            len.hash(state);
+        }
+    }
 }
 
 impl Boundary {
@@ -169,7 +297,7 @@ impl Boundary {
             // match the UpperLower boundary in the case of Acronym
             let two_iter = left_iter.clone().zip(mid_iter.clone());
             let mut two_iter_and_upper = two_iter.clone()
-                .zip(std::iter::once(false).chain(
+                .zip(core::iter::once(false).chain(
                         two_iter.map(|(a, b)| grapheme_is_uppercase(a) && grapheme_is_uppercase(b))
                 ));
 
@@ -181,9 +309,60 @@ impl Boundary {
         }).copied().collect()
     }
 
+    /// Creates a boundary that splits on the delimiter `delim`, consuming it on segmentation.
+    /// The delimiter can be any string, including one that spans multiple graphemes.
+    /// ```
+    /// use convert_case::{Boundary, Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .set_boundaries(&[Boundary::from_delim("::")])
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("std_fmt_display", conv.convert("std::fmt::Display"));
+    /// ```
+    pub fn from_delim(delim: &'static str) -> Self {
+        Boundary::Custom {
+            condition: starts_with_arg,
+            arg: Some(delim),
+            start: 0,
+            len: delim.graphemes(true).count(),
+        }
+    }
+
+    /// Like [`Boundary::from_delim`], but usable in constants.  Every byte of `delim` has to
+    /// be its own grapheme, as in ASCII text, since graphemes can't be counted in a const fn.
+    pub(crate) const fn from_ascii_delim(delim: &'static str) -> Self {
+        Boundary::Custom {
+            condition: starts_with_arg,
+            arg: Some(delim),
+            start: 0,
+            len: delim.len(),
+        }
+    }
+
+    /// Creates a boundary for each delimiter in `delims`, like [`Boundary::from_delim`].  The
+    /// boundaries are ordered longest delimiter first, so when one delimiter starts with
+    /// another, like `::` and `:`, the longer one is matched.
+    /// ```
+    /// use convert_case::{Boundary, Case, Converter};
+    ///
+    /// let conv = Converter::new()
+    ///     .set_boundaries(&Boundary::from_delims(&[":", ".", "::", "/"]))
+    ///     .to_case(Case::Snake);
+    /// assert_eq!("a_b_c_d_e", conv.convert("a.b::c/d:e"));
+    /// ```
+    pub fn from_delims(delims: &[&'static str]) -> Vec<Self> {
+        let mut delims = delims.to_vec();
+        delims.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        delims.dedup();
+        delims.into_iter().map(Boundary::from_delim).collect()
+    }
+
     /// The default list of boundaries used when `Casing::to_case` is called directly
     /// and in a `Converter` generated from `Converter::new()`.  This includes
     /// all the boundaries except the `UpperLower` boundary.
+    ///
+    /// Like the other lists of boundaries, this is a `const fn` returning an array, so getting
+    /// the list doesn't allocate.  Use `to_vec` when a `Vec` is needed.
     /// ```
     /// use convert_case::Boundary;
     /// use Boundary::*;
@@ -195,9 +374,9 @@ impl Boundary {
     ///     Boundary::defaults()
     /// );
     /// ```
-    pub fn defaults() -> Vec<Self> {
+    pub const fn defaults() -> [Self; 9] {
         use Boundary::*;
-        vec![
+        [
             Underscore, Hyphen, Space, LowerUpper, UpperDigit, DigitUpper, DigitLower, LowerDigit,
             Acronym,
         ]
@@ -213,9 +392,9 @@ impl Boundary {
     ///     Boundary::delims()
     /// );
     /// ```
-    pub fn delims() -> Vec<Self> {
+    pub const fn delims() -> [Self; 3] {
         use Boundary::*;
-        vec![Hyphen, Underscore, Space]
+        [Hyphen, Underscore, Space]
     }
 
     /// Returns the boundaries that involve digits: `DigitUpper`, `DigitLower`, `UpperDigit`, and
@@ -228,9 +407,24 @@ impl Boundary {
     ///     Boundary::digits()
     /// );
     /// ```
-    pub fn digits() -> Vec<Self> {
+    pub const fn digits() -> [Self; 4] {
         use Boundary::*;
-        vec![DigitUpper, UpperDigit, DigitLower, LowerDigit]
+        [DigitUpper, UpperDigit, DigitLower, LowerDigit]
+    }
+
+    /// Returns the boundaries that make each run of digits its own word: `LowerDigit`,
+    /// `UpperDigit`, `DigitLower`, and `DigitUpper`.  These are the same boundaries as
+    /// [`Boundary::digits`], listed in the order a run of digits is entered and left.  Digits
+    /// next to each other are never split, so `"2024"` stays a single word.
+    /// ```
+    /// use convert_case::{split, Boundary};
+    ///
+    /// assert_eq!(vec!["v", "2", "api", "30"], split("v2api30", &Boundary::digit_runs()));
+    /// assert_eq!(vec!["Model", "3", "D"], split("Model3D", &Boundary::digit_runs()));
+    /// ```
+    pub const fn digit_runs() -> [Self; 4] {
+        use Boundary::*;
+        [LowerDigit, UpperDigit, DigitLower, DigitUpper]
     }
 
     /// Returns the boundaries that are letters followed by digits: `UpperDigit` and `LowerDigit`.
@@ -242,9 +436,9 @@ impl Boundary {
     ///     Boundary::letter_digit()
     /// );
     /// ```
-    pub fn letter_digit() -> Vec<Self> {
+    pub const fn letter_digit() -> [Self; 2] {
         use Boundary::*;
-        vec![UpperDigit, LowerDigit]
+        [UpperDigit, LowerDigit]
     }
 
     /// Returns the boundaries that are digits followed by letters: `DigitUpper` and
@@ -257,9 +451,24 @@ impl Boundary {
     ///     Boundary::digit_letter()
     /// );
     /// ```
-    pub fn digit_letter() -> Vec<Self> {
+    pub const fn digit_letter() -> [Self; 2] {
+        use Boundary::*;
+        [DigitUpper, DigitLower]
+    }
+
+    /// Returns the boundaries that split on whitespace: `Space`, `Tab`, and `Newline`.
+    /// ```
+    /// use convert_case::{split, Boundary};
+    /// use Boundary::*;
+    /// assert_eq!(vec![Space, Tab, Newline], Boundary::whitespace());
+    /// assert_eq!(
+    ///     vec!["Name", "Age", "City"],
+    ///     split("Name\tAge \tCity\n", &Boundary::whitespace())
+    /// );
+    /// ```
+    pub const fn whitespace() -> [Self; 3] {
         use Boundary::*;
-        vec![DigitUpper, DigitLower]
+        [Space, Tab, Newline]
     }
 
     /// Returns all boundaries.  Note that this includes the `UpperLower` variant which
@@ -269,26 +478,89 @@ impl Boundary {
     /// use Boundary::*;
     /// assert_eq!(
     ///     vec![
-    ///         Hyphen, Underscore, Space, LowerUpper, UpperLower, DigitUpper,
+    ///         Hyphen, Underscore, Space, Tab, Newline, LowerUpper, UpperLower, DigitUpper,
     ///         UpperDigit, DigitLower, LowerDigit, Acronym,
     ///     ],
     ///     Boundary::all()
     /// );
     /// ```
-    pub fn all() -> Vec<Self> {
+    pub const fn all() -> [Self; 12] {
         use Boundary::*;
-        vec![
-            Hyphen, Underscore, Space, LowerUpper, UpperLower, DigitUpper, UpperDigit, 
-            DigitLower, LowerDigit, Acronym
+        [
+            Hyphen, Underscore, Space, Tab, Newline, LowerUpper, UpperLower, DigitUpper,
+            UpperDigit, DigitLower, LowerDigit, Acronym,
         ]
     }
 
+    /// Returns the boundaries in either `a` or `b`.  Like the other set operations, the
+    /// result has no duplicates and lists the boundaries in the order of [`Boundary::all`],
+    /// followed by custom boundaries in the order they first appear.
+    /// ```
+    /// use convert_case::Boundary;
+    /// use Boundary::*;
+    /// assert_eq!(
+    ///     vec![Hyphen, Underscore, LowerUpper, DigitUpper],
+    ///     Boundary::union(&[DigitUpper, Underscore], &[LowerUpper, Hyphen, Underscore])
+    /// );
+    /// ```
+    pub fn union(a: &[Boundary], b: &[Boundary]) -> Vec<Self> {
+        Boundary::canonical(a.iter().chain(b))
+    }
+
+    /// Returns the boundaries in both `a` and `b`, in the same order as [`Boundary::union`].
+    /// ```
+    /// use convert_case::Boundary;
+    /// use Boundary::*;
+    /// assert_eq!(
+    ///     vec![Underscore, LowerUpper],
+    ///     Boundary::intersection(&[LowerUpper, Underscore, Space], &[Underscore, LowerUpper])
+    /// );
+    /// ```
+    pub fn intersection(a: &[Boundary], b: &[Boundary]) -> Vec<Self> {
+        Boundary::canonical(a.iter().filter(|boundary| b.contains(boundary)))
+    }
+
+    /// Returns the boundaries in `a` that aren't in `b`, in the same order as
+    /// [`Boundary::union`].
+    /// ```
+    /// use convert_case::Boundary;
+    /// use Boundary::*;
+    /// assert_eq!(
+    ///     vec![Hyphen, Underscore, Space, LowerUpper, Acronym],
+    ///     Boundary::difference(&Boundary::defaults(), &Boundary::digits())
+    /// );
+    /// ```
+    pub fn difference(a: &[Boundary], b: &[Boundary]) -> Vec<Self> {
+        Boundary::canonical(a.iter().filter(|boundary| !b.contains(boundary)))
+    }
+
+    /// Collects boundaries without duplicates, in the order of `Boundary::all` followed by
+    /// custom boundaries in the order they appear.
+    fn canonical<'a, I>(boundaries: I) -> Vec<Self>
+    where
+        I: Iterator<Item = &'a Boundary>,
+    {
+        let boundaries: Vec<Boundary> = boundaries.copied().collect();
+        let mut canonical: Vec<Boundary> = Boundary::all()
+            .into_iter()
+            .filter(|boundary| boundaries.contains(boundary))
+            .collect();
+        for boundary in boundaries {
+            if !canonical.contains(&boundary) {
+                canonical.push(boundary);
+            }
+        }
+        canonical
+    }
+
     fn detect_one(&self, c: &str) -> bool {
         use Boundary::*;
         match self {
             Hyphen => c == "-",
             Underscore => c == "_",
             Space => c == " ",
+            Tab => c == "\t",
+            Newline => c == "\n" || c == "\r\n",
             _ => false,
         }
     }
@@ -316,103 +588,434 @@ impl Boundary {
             false
         }
     }
+
+    /// The bit for this boundary in [`BoundaryFlags`], or `None` for `Custom`.
+    const fn flag(&self) -> Option<u16> {
+        use Boundary::*;
+        let bit = match self {
+            Hyphen => 0,
+            Underscore => 1,
+            Space => 2,
+            UpperLower => 3,
+            LowerUpper => 4,
+            DigitUpper => 5,
+            UpperDigit => 6,
+            DigitLower => 7,
+            LowerDigit => 8,
+            Acronym => 9,
+            Tab => 10,
+            Newline => 11,
+            Custom { .. } => return None,
+        };
+        Some(1 << bit)
+    }
+
+    /// Returns the delimiter this boundary splits on and consumes, if it is `Hyphen`,
+    /// `Underscore`, `Space`, `Tab`, or a boundary created by [`Boundary::from_delim`].
+    /// `Newline` has no single delimiter, since it also splits on `\r\n`.
+    pub(crate) fn delim(&self) -> Option<&'static str> {
+        use Boundary::*;
+        match self {
+            Hyphen => Some("-"),
+            Underscore => Some("_"),
+            Space => Some(" "),
+            Tab => Some("\t"),
+            Custom {
+                condition,
+                arg: Some(d),
+                start: 0,
+                len,
+            } if core::ptr::fn_addr_eq(*condition, starts_with_arg as BoundaryCondition)
+                && *len == d.graphemes(true).count() =>
+            {
+                Some(d)
+            }
+            _ => None,
+        }
+    }
+
+    /// Detects the boundary at the start of `rest`, whose first three graphemes are `window`
+    /// (padded with empty strings).  Returns the byte offset into `rest` where the split
+    /// occurs and the number of bytes consumed there.
+    fn detect(&self, rest: &str, window: &[&str; 3]) -> Option<(usize, usize)> {
+        use Boundary::*;
+        let [c, d, e] = *window;
+        match self {
+            Hyphen | Underscore | Space | Tab | Newline => {
+                self.detect_one(c).then_some((0, c.len()))
+            }
+            // the window is padded with empty strings at the end of the string
+            UpperLower | LowerUpper | DigitUpper | UpperDigit | DigitLower | LowerDigit => {
+                (!d.is_empty() && self.detect_two(c, d)).then_some((c.len(), 0))
+            }
+            Acronym => (!e.is_empty() && self.detect_three(c, d, e)).then_some((c.len(), 0)),
+            Custom {
+                condition,
+                arg,
+                start,
+                len,
+            } => condition(rest, *arg).then(|| {
+                let split_start = grapheme_offset(rest, *start);
+                let consumed = grapheme_offset(&rest[split_start..], *len);
+                (split_start, consumed)
+            }),
+        }
+    }
+}
+
+/// Byte length of the first `n` graphemes of `s`, or the length of `s` if it is shorter.
+fn grapheme_offset(s: &str, n: usize) -> usize {
+    s.grapheme_indices(true).nth(n).map_or(s.len(), |(i, _)| i)
 }
 
 fn grapheme_is_digit(c: &str) -> bool {
     c.chars().all(|c| c.is_ascii_digit())
 }
 
+// The case checks compare chars lazily instead of allocating with `str::to_uppercase`.  A
+// grapheme is uppercase if it has a case and is equal to its own uppercase form.
+
 fn grapheme_is_uppercase(c: &str) -> bool {
-    c.to_uppercase() != c.to_lowercase() && c == c.to_uppercase()
+    grapheme_has_case(c) && c.chars().eq(c.chars().flat_map(char::to_uppercase))
 }
 
 fn grapheme_is_lowercase(c: &str) -> bool {
-    c.to_uppercase() != c.to_lowercase() && c == c.to_lowercase()
+    grapheme_has_case(c) && c.chars().eq(c.chars().flat_map(char::to_lowercase))
+}
+
+fn grapheme_has_case(c: &str) -> bool {
+    !c.chars()
+        .flat_map(char::to_uppercase)
+        .eq(c.chars().flat_map(char::to_lowercase))
+}
+
+/// The kind of a single grapheme, as far as the built-in boundaries are concerned.
+#[derive(Clone, Copy, PartialEq)]
+enum GraphemeClass {
+    Upper,
+    Lower,
+    Digit,
+    Other,
 }
 
-pub fn split<T>(s: T, boundaries: &[Boundary]) -> Vec<String>
+impl GraphemeClass {
+    fn of(g: &str) -> Self {
+        if let [b] = g.as_bytes() {
+            return match b {
+                b'A'..=b'Z' => GraphemeClass::Upper,
+                b'a'..=b'z' => GraphemeClass::Lower,
+                b'0'..=b'9' => GraphemeClass::Digit,
+                _ => GraphemeClass::Other,
+            };
+        }
+        if g.is_empty() {
+            GraphemeClass::Other
+        } else if grapheme_is_digit(g) {
+            GraphemeClass::Digit
+        } else if grapheme_is_uppercase(g) {
+            GraphemeClass::Upper
+        } else if grapheme_is_lowercase(g) {
+            GraphemeClass::Lower
+        } else {
+            GraphemeClass::Other
+        }
+    }
+}
+
+/// The built-in boundaries in a boundary list, one bit per variant, so they can all be checked
+/// at once instead of one at a time.  Only a single built-in boundary can match at any
+/// grapheme, so the order of the list doesn't matter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct BoundaryFlags(u16);
+
+impl BoundaryFlags {
+    /// The flags for `boundaries`, or `None` if any of them is `Custom`.
+    fn new(boundaries: &[Boundary]) -> Option<Self> {
+        boundaries
+            .iter()
+            .try_fold(0, |flags, b| Some(flags | b.flag()?))
+            .map(BoundaryFlags)
+    }
+
+    fn contains(self, boundary: Boundary) -> bool {
+        boundary.flag().is_some_and(|flag| self.0 & flag != 0)
+    }
+
+    /// The boundary that matches at the start of `window`, padded with empty strings as in
+    /// `Boundary::detect`.
+    fn detect(self, [c, d, e]: &[&str; 3]) -> Option<Boundary> {
+        use Boundary::*;
+        use GraphemeClass::*;
+        let found = match *c {
+            "-" => Hyphen,
+            "_" => Underscore,
+            " " => Space,
+            "\t" => Tab,
+            "\n" | "\r\n" => Newline,
+            _ => match (GraphemeClass::of(c), GraphemeClass::of(d)) {
+                (Upper, Lower) => UpperLower,
+                (Lower, Upper) => LowerUpper,
+                (Digit, Upper) => DigitUpper,
+                (Upper, Digit) => UpperDigit,
+                (Digit, Lower) => DigitLower,
+                (Lower, Digit) => LowerDigit,
+                (Upper, Upper) if GraphemeClass::of(e) == Lower => Acronym,
+                _ => return None,
+            },
+        };
+        self.contains(found).then_some(found)
+    }
+}
+
+/// Splits a string into words using the given boundaries.  Empty words, like those between
+/// two delimiters in a row, are removed.
+/// ```
+/// use convert_case::{split, Boundary};
+///
+/// assert_eq!(
+///     vec!["my", "var", "Name"],
+///     split("my__var-Name", &[Boundary::Underscore, Boundary::Hyphen])
+/// );
+/// ```
+pub fn split<'s, T>(s: &'s T, boundaries: &[Boundary]) -> Vec<&'s str>
 where
-    T: AsRef<str>,
+    T: AsRef<str> + ?Sized,
 {
-    use std::iter::once;
-    // create split_points function that counts off by graphemes into list
-    
-    let s = s.as_ref();
-
-    // Some<bool> means the following
-    // None: no split
-    // Some(false): split between characters
-    // Some(true): split consuming characters
-
-    let left_iter = s.graphemes(true);
-    let mid_iter = s.graphemes(true).skip(1);
-    let right_iter = s.graphemes(true).skip(2);
-
-    let singles = left_iter.clone();
-    let doubles = left_iter.clone().zip(mid_iter.clone());
-    let triples = left_iter.zip(mid_iter).zip(right_iter);
-
-    let singles = singles
-        .map(|c| boundaries.iter().any(|b| b.detect_one(c)))
-        .map(|split| if split {Some(true)} else {None});
-    let doubles = doubles
-        .map(|(c,d)| boundaries.iter().any(|b| b.detect_two(c, d)))
-        .map(|split| if split {Some(false)} else {None});
-    let triples = triples
-        .map(|((c,d),e)| boundaries.iter().any(|b| b.detect_three(c, d, e)))
-        .map(|split| if split {Some(false)} else {None});
-
-    let split_points = singles
-        .zip(once(None).chain(doubles))
-        .zip(once(None).chain(triples).chain(once(None)))
-        .map(|((s, d), t)| s.or(d).or(t));
-
-    let mut words = Vec::new();
-    let mut word = String::new();
-    for (c, split) in s.graphemes(true).zip(split_points) {
-        match split {
-            // no split here
-            None => word.push_str(c),
-            // split here, consume letter
-            Some(true) => words.push(std::mem::take(&mut word)),
-            // split here, keep letter
-            Some(false) => {
-                words.push(std::mem::take(&mut word));
-                word.push_str(c);
+    split_iter(s, boundaries).collect()
+}
+
+/// Splits a string into words using the given boundaries, yielding each word as it is
+/// found instead of collecting them into a `Vec`.  Yields the same words as [`split`].
+/// ```
+/// use convert_case::{split_iter, Boundary};
+///
+/// let mut words = split_iter("getTotalLength", &[Boundary::LowerUpper]);
+/// assert_eq!(Some("get"), words.next());
+/// assert_eq!(2, words.count());
+/// ```
+pub fn split_iter<'s, 'b, T>(
+    s: &'s T,
+    boundaries: &'b [Boundary],
+) -> impl Iterator<Item = &'s str> + 'b
+where
+    's: 'b,
+    T: AsRef<str> + ?Sized,
+{
+    Words::new(s.as_ref(), Cow::Borrowed(boundaries))
+}
+
+/// A boundary backed by a closure, added with `Converter::add_boundary_fn`.  It is given the
+/// rest of the string starting at the current grapheme and returns the number of bytes to
+/// consume there if the string should be split.
+pub(crate) type BoundaryFn = Arc<dyn Fn(&str) -> Option<usize> + Send + Sync>;
+
+/// Boundary lists at least this long are indexed by `DelimIndex` before splitting.
+const WIDE_BOUNDARIES: usize = 16;
+
+/// Positions of boundaries in a boundary list, with delimiter boundaries grouped by the first
+/// character of their delimiter.  A delimiter can only match where the string starts with
+/// that character, so the other delimiters can be skipped without checking them.
+struct DelimIndex {
+    by_first_char: BTreeMap<char, Vec<usize>>,
+    others: Vec<usize>,
+}
+
+impl DelimIndex {
+    fn new(boundaries: &[Boundary]) -> Self {
+        let mut by_first_char: BTreeMap<char, Vec<usize>> = BTreeMap::new();
+        let mut others = Vec::new();
+        for (i, b) in boundaries.iter().enumerate() {
+            match b.delim().and_then(|delim| delim.chars().next()) {
+                Some(first) => by_first_char.entry(first).or_default().push(i),
+                None => others.push(i),
             }
         }
+        DelimIndex {
+            by_first_char,
+            others,
+        }
+    }
+
+    /// Positions of the boundaries that could match at the start of `rest`, in list order.
+    fn candidates<'a>(&'a self, rest: &str) -> impl Iterator<Item = usize> + 'a {
+        let delims = rest
+            .chars()
+            .next()
+            .and_then(|c| self.by_first_char.get(&c))
+            .map_or(&[][..], Vec::as_slice);
+        let (mut delims, mut others) = (delims.iter().peekable(), self.others.iter().peekable());
+        core::iter::from_fn(move || match (delims.peek(), others.peek()) {
+            (Some(d), Some(o)) if d < o => delims.next().copied(),
+            (Some(_), None) => delims.next().copied(),
+            _ => others.next().copied(),
+        })
+    }
+}
+
+type BoundaryCondition = fn(&str, Option<&'static str>) -> bool;
+
+/// The condition of boundaries made by [`Boundary::from_delim`].  It is a named function so
+/// `Boundary::delim` can tell those boundaries apart from other custom boundaries with an
+/// `arg`, which compare equal to them.
+fn starts_with_arg(s: &str, arg: Option<&'static str>) -> bool {
+    arg.is_some_and(|delim| s.starts_with(delim))
+}
+
+/// Iterator over the words of a string, used by `split_iter` and `Case::split_iter`.
+pub(crate) struct Words<'s, 'b> {
+    s: &'s str,
+    boundaries: Cow<'b, [Boundary]>,
+    // set when there are no custom boundaries, so they can be checked with bitwise operations
+    flags: Option<BoundaryFlags>,
+    // only built for long boundary lists, where scanning every boundary is slow
+    index: Option<DelimIndex>,
+    boundary_fns: &'b [BoundaryFn],
+    // the `Acronym` boundary only splits runs of uppercase letters at least this long, or
+    // runs whose acronym is one of `known_acronyms`
+    acronym_min_len: usize,
+    known_acronyms: &'b [String],
+    graphemes: GraphemeIndices<'s>,
+    // the two graphemes after the current one, so the window doesn't re-walk the string
+    ahead: [Option<(usize, &'s str)>; 2],
+    word_start: usize,
+    done: bool,
+}
+
+impl<'s, 'b> Words<'s, 'b> {
+    pub(crate) fn new(s: &'s str, boundaries: Cow<'b, [Boundary]>) -> Self {
+        let mut graphemes = s.grapheme_indices(true);
+        let ahead = [graphemes.next(), graphemes.next()];
+        let flags = BoundaryFlags::new(&boundaries);
+        let index = (flags.is_none() && boundaries.len() >= WIDE_BOUNDARIES)
+            .then(|| DelimIndex::new(&boundaries));
+        Words {
+            s,
+            boundaries,
+            flags,
+            index,
+            boundary_fns: &[],
+            acronym_min_len: 0,
+            known_acronyms: &[],
+            graphemes,
+            ahead,
+            word_start: 0,
+            done: false,
+        }
+    }
+
+    /// Moves to the next grapheme, returning it along with its byte index.
+    fn advance(&mut self) -> Option<(usize, &'s str)> {
+        let current = self.ahead[0];
+        self.ahead = [self.ahead[1], self.graphemes.next()];
+        current
+    }
+
+    /// Detects the first boundary in the list that matches at the start of `rest`.
+    fn detect(&self, rest: &str, window: &[&str; 3]) -> Option<(usize, usize)> {
+        if let Some(flags) = self.flags {
+            let c = window[0];
+            return match flags.detect(window)? {
+                Boundary::Hyphen
+                | Boundary::Underscore
+                | Boundary::Space
+                | Boundary::Tab
+                | Boundary::Newline => Some((0, c.len())),
+                Boundary::Acronym if !self.is_acronym(rest, c) => None,
+                _ => Some((c.len(), 0)),
+            };
+        }
+        let detect = |b: &Boundary| {
+            b.detect(rest, window)
+                .filter(|_| *b != Boundary::Acronym || self.is_acronym(rest, window[0]))
+        };
+        match &self.index {
+            Some(index) => index
+                .candidates(rest)
+                .find_map(|i| detect(&self.boundaries[i])),
+            None => self.boundaries.iter().find_map(detect),
+        }
+    }
+
+    /// Whether the run of uppercase letters ending with the grapheme after `c` is long enough,
+    /// or known, to be split as an acronym.  `rest` starts with `c`.
+    fn is_acronym(&self, rest: &str, c: &str) -> bool {
+        // the acronym boundary always has at least two uppercase letters in its run
+        if self.acronym_min_len <= 2 {
+            return true;
+        }
+        let end = self.s.len() - rest.len() + c.len();
+        let before = &self.s[self.word_start..end];
+        let acronym_len: usize = before
+            .graphemes(true)
+            .rev()
+            .take_while(|g| grapheme_is_uppercase(g))
+            .map(str::len)
+            .sum();
+        let acronym = &before[before.len() - acronym_len..];
+        acronym.graphemes(true).count() + 1 >= self.acronym_min_len
+            || self
+                .known_acronyms
+                .iter()
+                .any(|known| known.to_lowercase() == acronym.to_lowercase())
     }
-    words.push(word);
-
-    /*
-    let mut words = Vec::new();
-    let mut left_idx = 0;
-    let mut total_chars = 0;
-    let mut skip = 0;
-    let mut cur = GraphemeCursor::new(left_idx, s.len(), true);
-
-    for (right_idx, split) in split_points.enumerate() {
-        match split {
-            // no split here
-            None => {},
-            // split here, consume letter
-            Some(true) => {
-                let mut right_bound = left_bound;
-                for _ in 0..total_chars {
-                    right_bound = cur.next_boundary(s, skip).unwrap().unwrap();
+
+    /// Also splits wherever one of `boundary_fns` matches, after checking `boundaries`.
+    pub(crate) fn with_boundary_fns(self, boundary_fns: &'b [BoundaryFn]) -> Self {
+        Words {
+            boundary_fns,
+            ..self
+        }
+    }
+
+    /// Only splits with the `Acronym` boundary when the run of uppercase letters is at least
+    /// `min_len` long, or when the letters before the split are one of `known_acronyms`.
+    pub(crate) fn with_acronym_rules(self, min_len: usize, known_acronyms: &'b [String]) -> Self {
+        Words {
+            acronym_min_len: min_len,
+            known_acronyms,
+            ..self
+        }
+    }
+}
+
+impl<'s> Iterator for Words<'s, '_> {
+    type Item = &'s str;
+
+    fn next(&mut self) -> Option<&'s str> {
+        let s = self.s;
+        while let Some((i, c)) = self.advance() {
+            let [d, e] = self.ahead.map(|g| g.map_or("", |(_, g)| g));
+            let window = [c, d, e];
+            let rest = &s[i..];
+
+            let found = self.detect(rest, &window).or_else(|| {
+                self.boundary_fns
+                    .iter()
+                    .find_map(|f| f(rest).filter(|&len| rest.is_char_boundary(len)))
+                    .map(|len| (0, len))
+            });
+            if let Some((split, consumed)) = found {
+                let word = &s[self.word_start..i + split];
+                self.word_start = i + split + consumed;
+
+                // don't look for boundaries inside what was just consumed
+                while self.ahead[0].is_some_and(|(j, _)| j < self.word_start) {
+                    self.advance();
+                }
+
+                if !word.is_empty() {
+                    return Some(word);
                 }
-                words.push(&s[left_bound..right_bound])
-            }
-            // split here, keep letter
-            Some(false) => {
             }
-            // dont push an empty string, do nothing
-            _ => {}
         }
-    }
-    */
 
-    words.into_iter().filter(|s| !s.is_empty()).collect()
+        if self.done {
+            return None;
+        }
+        self.done = true;
+        let word = &s[self.word_start..];
+        (!word.is_empty()).then_some(word)
+    }
 }
 
 #[cfg(test)]
@@ -436,6 +1039,454 @@ mod test {
         )
     }
 
+    #[test]
+    fn split_on_whitespace() {
+        assert_eq!(
+            vec!["a", "b", "c"],
+            split("a\tb c", &Boundary::whitespace())
+        );
+        assert_eq!(vec!["One", "two"], split("One\ntwo", &[Boundary::Newline]));
+        assert_eq!(
+            vec!["One", "two"],
+            split("One\r\ntwo", &[Boundary::Newline])
+        );
+        assert_eq!(vec!["One\ntwo"], split("One\ntwo", &Boundary::defaults()));
+        assert_eq!(
+            vec!["a", "b"],
+            split("\t\ta\t \n b\n", &Boundary::whitespace())
+        );
+    }
+
+    #[test]
+    fn split_on_digit_runs() {
+        assert_eq!(
+            vec!["v", "2", "api", "3"],
+            split("v2api3", &Boundary::digit_runs())
+        );
+        assert_eq!(vec!["123"], split("123", &Boundary::digit_runs()));
+        assert_eq!(
+            vec!["a", "12", "B", "345", "c"],
+            split("a12B345c", &Boundary::digit_runs())
+        );
+        assert_eq!(
+            vec!["getTotal", "2"],
+            split("getTotal2", &Boundary::digit_runs())
+        );
+    }
+
+    #[test]
+    fn split_on_custom_delim() {
+        assert_eq!(
+            vec!["com", "xenoterracide", "app"],
+            split("com.xenoterracide.app", &[Boundary::from_delim(".")])
+        );
+        assert_eq!(
+            vec!["std", "fmt", "Display"],
+            split("::std::fmt::Display", &[Boundary::from_delim("::")])
+        );
+    }
+
+    #[test]
+    fn split_on_custom_and_builtin() {
+        assert_eq!(
+            vec!["my", "Module", "sub", "Module"],
+            split(
+                "myModule/subModule",
+                &[Boundary::from_delim("/"), Boundary::LowerUpper]
+            )
+        );
+    }
+
+    #[test]
+    fn split_iter_matches_split() {
+        let boundaries = Boundary::defaults();
+        for s in [
+            "getTotalLength",
+            "__leading_and__double__delims_",
+            "XMLHttpRequest2Go",
+            "",
+            "---",
+            "one",
+        ] {
+            assert_eq!(
+                split(s, &boundaries),
+                split_iter(s, &boundaries).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn split_iter_skips_empty_words() {
+        let mut words = split_iter("-a--b-", &[Boundary::Hyphen]);
+        assert_eq!(Some("a"), words.next());
+        assert_eq!(Some("b"), words.next());
+        assert_eq!(None, words.next());
+        assert_eq!(None, words.next());
+    }
+
+    /// The segmentation before the lookahead window was cached, kept to check that the
+    /// optimized `split` gives identical words.
+    fn reference_split<'s>(s: &'s str, boundaries: &[Boundary]) -> Vec<&'s str> {
+        fn is_upper(c: &str) -> bool {
+            c.to_uppercase() != c.to_lowercase() && c == c.to_uppercase()
+        }
+        fn is_lower(c: &str) -> bool {
+            c.to_uppercase() != c.to_lowercase() && c == c.to_lowercase()
+        }
+        fn is_digit(c: &str) -> bool {
+            !c.is_empty() && c.chars().all(|c| c.is_ascii_digit())
+        }
+        fn detect(b: &Boundary, rest: &str, [c, d, e]: [&str; 3]) -> Option<(usize, usize)> {
+            use Boundary::*;
+            let found = match b {
+                Hyphen => c == "-",
+                Underscore => c == "_",
+                Space => c == " ",
+                Tab => c == "\t",
+                Newline => c == "\n" || c == "\r\n",
+                UpperLower => is_upper(c) && is_lower(d),
+                LowerUpper => is_lower(c) && is_upper(d),
+                DigitUpper => is_digit(c) && is_upper(d),
+                UpperDigit => is_upper(c) && is_digit(d),
+                DigitLower => is_digit(c) && is_lower(d),
+                LowerDigit => is_lower(c) && is_digit(d),
+                Acronym => is_upper(c) && is_upper(d) && is_lower(e),
+                Custom { .. } => return b.detect(rest, &[c, d, e]),
+            };
+            match b {
+                Hyphen | Underscore | Space | Tab | Newline => found.then_some((0, c.len())),
+                _ => found.then_some((c.len(), 0)),
+            }
+        }
+
+        let mut words = Vec::new();
+        let mut word_start = 0;
+        let mut graphemes = s.grapheme_indices(true);
+        while let Some((i, c)) = graphemes.next() {
+            let mut ahead = graphemes.clone().map(|(_, g)| g);
+            let window = [c, ahead.next().unwrap_or(""), ahead.next().unwrap_or("")];
+            if let Some((split, consumed)) =
+                boundaries.iter().find_map(|b| detect(b, &s[i..], window))
+            {
+                words.push(&s[word_start..i + split]);
+                word_start = i + split + consumed;
+                while graphemes
+                    .clone()
+                    .next()
+                    .is_some_and(|(j, _)| j < word_start)
+                {
+                    graphemes.next();
+                }
+            }
+        }
+        words.push(&s[word_start..]);
+        words.into_iter().filter(|s| !s.is_empty()).collect()
+    }
+
+    #[test]
+    fn split_matches_reference() {
+        let pieces = [
+            "a", "b", "Z", "Q", "1", "9", "-", "_", " ", "::", "é", "É", "e\u{301}", "ß", "Σ", "ǅ",
+            "ℍ", "ﬁ", "П", "ж", "👍", ".", ":", "/", "\t", "\n", "\r",
+        ];
+        let boundary_lists = [
+            Boundary::defaults().to_vec(),
+            Boundary::all().to_vec(),
+            vec![Boundary::Acronym, Boundary::UpperLower],
+            vec![
+                Boundary::from_delim("::"),
+                Boundary::LowerUpper,
+                Boundary::DigitLower,
+            ],
+            wide_boundaries(),
+        ];
+
+        assert!(boundary_lists[4].len() >= WIDE_BOUNDARIES);
+
+        // a small linear congruential generator, so the test is reproducible
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+        for _ in 0..2000 {
+            let len = next() % 12;
+            let s: String = (0..len).map(|_| pieces[next() % pieces.len()]).collect();
+            for boundaries in &boundary_lists {
+                assert_eq!(
+                    reference_split(&s, boundaries),
+                    split(&s, boundaries),
+                    "{:?}",
+                    s
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn flags_match_general_path() {
+        let pieces = [
+            "a", "b", "Z", "Q", "1", "-", "_", " ", "é", "É", "e\u{301}", "ß", "ǅ", "👍", "\t",
+            "\n", "\r",
+        ];
+        let boundary_lists = [
+            Boundary::defaults().to_vec(),
+            Boundary::all().to_vec(),
+            Boundary::whitespace().to_vec(),
+            Boundary::delims().to_vec(),
+            Boundary::digits().to_vec(),
+            vec![Boundary::Acronym],
+            vec![Boundary::LowerUpper, Boundary::Acronym, Boundary::Hyphen],
+        ];
+        let known = vec!["ABC".to_string()];
+
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+        for _ in 0..2000 {
+            let len = next() % 12;
+            let s: String = (0..len).map(|_| pieces[next() % pieces.len()]).collect();
+            for boundaries in &boundary_lists {
+                for min_len in [0, 4] {
+                    let fast = Words::new(&s, Cow::Borrowed(boundaries))
+                        .with_acronym_rules(min_len, &known);
+                    let mut general = Words::new(&s, Cow::Borrowed(boundaries))
+                        .with_acronym_rules(min_len, &known);
+                    assert!(fast.flags.is_some());
+                    general.flags = None;
+                    assert_eq!(
+                        general.collect::<Vec<_>>(),
+                        fast.collect::<Vec<_>>(),
+                        "{:?} {:?}",
+                        s,
+                        boundaries
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn flags_only_without_custom_boundaries() {
+        let flags = BoundaryFlags::new(&Boundary::defaults()).unwrap();
+        assert!(Boundary::defaults().iter().all(|b| flags.contains(*b)));
+        assert!(!flags.contains(Boundary::UpperLower));
+        assert_eq!(
+            None,
+            BoundaryFlags::new(&[Boundary::Hyphen, Boundary::from_delim(".")])
+        );
+        assert_eq!(Some(BoundaryFlags(0)), BoundaryFlags::new(&[]));
+    }
+
+    /// Every boundary along with 20 delimiters, enough to be indexed when splitting.
+    fn wide_boundaries() -> Vec<Boundary> {
+        let mut boundaries = vec![Boundary::from_delim(":")];
+        boundaries.extend(Boundary::all());
+        boundaries.extend(Boundary::from_delims(&[
+            "::", ".", "/", "\\", "|", "+", "=", "~", "#", "@", "e\u{301}", "ß", "👍", "ﬁ", "П",
+            "<", ">", ",", ";",
+        ]));
+        boundaries
+    }
+
+    // the lists of boundaries can be built at compile time
+    const _: [Boundary; 9] = Boundary::defaults();
+    const _: [Boundary; 12] = Boundary::all();
+    const _: [Boundary; 3] = Boundary::whitespace();
+    const _: [Boundary; 3] = Boundary::delims();
+    const _: [Boundary; 4] = Boundary::digits();
+    const _: [Boundary; 4] = Boundary::digit_runs();
+
+    fn assert_copy<T: Copy>(_: T) {}
+
+    #[test]
+    fn boundaries_are_copy() {
+        for boundary in Boundary::all() {
+            assert_copy(boundary);
+        }
+        assert_copy(Boundary::from_delim("::"));
+    }
+
+    #[test]
+    fn custom_boundary_copies_equal() {
+        let custom = Boundary::Custom {
+            condition: |s, _| s.starts_with('.'),
+            arg: None,
+            start: 0,
+            len: 1,
+        };
+        let copied = custom;
+        assert_eq!(custom, copied);
+        assert_eq!(vec![custom], [copied].to_vec());
+    }
+
+    #[test]
+    fn custom_boundaries_equal_by_arg() {
+        let first = Boundary::from_delim("::");
+        let second = Boundary::Custom {
+            condition: |s, arg| arg.is_some_and(|delim| s.starts_with(delim)),
+            arg: Some("::"),
+            start: 0,
+            len: 2,
+        };
+        assert_eq!(first, second);
+        assert_ne!(first, Boundary::from_delim(":"));
+        let shorter = Boundary::Custom {
+            condition: |_, _| true,
+            arg: Some("::"),
+            start: 0,
+            len: 1,
+        };
+        assert_ne!(first, shorter);
+
+        let without_arg = |condition| Boundary::Custom {
+            condition,
+            arg: None,
+            start: 0,
+            len: 1,
+        };
+        let dot = without_arg(|s, _| s.starts_with('.'));
+        let comma = without_arg(|s, _| s.starts_with(','));
+        assert_eq!(dot, dot);
+        assert_ne!(dot, comma);
+    }
+
+    #[test]
+    fn custom_boundary_with_arg_is_not_a_delim() {
+        let dot_or_comma = Boundary::Custom {
+            condition: |s, arg| arg.is_some_and(|d| s.starts_with(d)) || s.starts_with(','),
+            arg: Some("."),
+            start: 0,
+            len: 1,
+        };
+        assert_eq!(None, dot_or_comma.delim());
+        assert_eq!(Some("."), Boundary::from_delim(".").delim());
+
+        let mut wide = Boundary::all().to_vec();
+        wide.extend(Boundary::from_delims(&["::", "->", "=>", "|"]));
+        wide.push(dot_or_comma);
+        assert!(wide.len() >= WIDE_BOUNDARIES);
+        assert_eq!(vec!["a", "b", "c"], split(&"a.b,c", &wide));
+        assert_eq!(vec!["a", "b", "c"], split(&"a.b,c", &[dot_or_comma]));
+    }
+
+    #[test]
+    fn boundaries_hash_like_eq() {
+        use std::collections::HashSet;
+
+        let set: HashSet<Boundary> = [
+            Boundary::Hyphen,
+            Boundary::Hyphen,
+            Boundary::from_delim("::"),
+            Boundary::from_delim("::"),
+            Boundary::from_delim("."),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(3, set.len());
+        assert!(set.contains(&Boundary::from_delim("::")));
+        assert!(!set.contains(&Boundary::Underscore));
+    }
+
+    #[test]
+    fn boundary_set_operations() {
+        use Boundary::*;
+        let defaults = Boundary::defaults().to_vec();
+        let digits = Boundary::digits().to_vec();
+        assert_eq!(
+            vec![
+                Hyphen, Underscore, Space, LowerUpper, DigitUpper, UpperDigit, DigitLower,
+                LowerDigit, Acronym
+            ],
+            Boundary::union(&defaults, &digits)
+        );
+        assert_eq!(
+            Boundary::union(&defaults, &digits),
+            Boundary::union(&digits, &defaults)
+        );
+        assert_eq!(digits, Boundary::intersection(&defaults, &digits));
+        assert_eq!(
+            vec![Hyphen, Underscore, Space, LowerUpper, Acronym],
+            Boundary::difference(&defaults, &digits)
+        );
+        assert_eq!(
+            Vec::<Boundary>::new(),
+            Boundary::difference(&digits, &defaults)
+        );
+        assert_eq!(
+            Boundary::all().to_vec(),
+            Boundary::union(&Boundary::all(), &Boundary::all())
+        );
+    }
+
+    #[test]
+    fn boundary_set_operations_keep_custom_order() {
+        use Boundary::*;
+        let customs = Boundary::from_delims(&["::", ":", "."]);
+        let union = Boundary::union(&customs, &[Space]);
+        assert_eq!(Space, union[0]);
+        assert_eq!(customs, union[1..]);
+        assert_eq!(
+            vec![customs[1]],
+            Boundary::intersection(&customs, &[customs[1], Hyphen])
+        );
+        assert_eq!(
+            vec![customs[0], customs[2]],
+            Boundary::difference(&customs, &[customs[1]])
+        );
+    }
+
+    #[test]
+    fn custom_and_built_in_boundaries_split_together() {
+        let dot = Boundary::from_delim(".");
+        assert_eq!(vec!["a", "b", "c"], split(&"a.b.c", &[dot]));
+
+        let mut boundaries = Boundary::defaults().to_vec();
+        boundaries.push(dot);
+        assert_eq!(
+            vec!["my", "Var", "name", "x"],
+            split(&"myVar.name_x", &boundaries)
+        );
+        assert_eq!(
+            split(&"a.b.c", &[dot]),
+            split_iter("a.b.c", &boundaries).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn from_delims_longest_first() {
+        let boundaries = Boundary::from_delims(&[".", ":", "::", "/", "."]);
+        assert_eq!(vec!["a", "b", "c", "d"], split(&"a.b::c/d", &boundaries));
+        assert_eq!(vec!["a", "b"], split(&"a::b", &boundaries));
+        assert_eq!(4, boundaries.len());
+        assert_eq!(Boundary::from_delim("::"), boundaries[0]);
+    }
+
+    #[test]
+    fn from_delim_multi_byte_single_grapheme() {
+        let middle_dot = Boundary::from_delim("·");
+        assert_eq!(vec!["a", "b", "c"], split(&"a·b·c", &[middle_dot]));
+        assert_eq!(vec!["ab", "cd"], split(&"ab·cd", &[middle_dot]));
+        assert_eq!(vec!["a", "b"], split(&"a—b", &[Boundary::from_delim("—")]));
+        assert_eq!(
+            vec!["a", "b"],
+            split(&"a—·b", &[Boundary::from_delim("—·")])
+        );
+    }
+
+    #[test]
+    fn from_delim_equality() {
+        assert_eq!(Boundary::from_delim("/"), Boundary::from_delim("/"));
+        assert_ne!(Boundary::from_delim("/"), Boundary::from_delim("."));
+        assert_ne!(Boundary::from_delim("-"), Boundary::Hyphen);
+    }
+
     #[test]
     fn boundaries_found_in_string() {
         use Boundary::*;
@@ -448,7 +1499,7 @@ mod test {
             Boundary::list_from("a8.Aa.aA")
         );
         assert_eq!(
-            Boundary::digits(),
+            Boundary::digits().to_vec(),
             Boundary::list_from("b1B1b")
         );
         assert_eq!(
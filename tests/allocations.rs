@@ -0,0 +1,56 @@
+//! Checks that the lazy splitting paths don't allocate.  The allocations of each thread are
+//! counted, so tests running in parallel don't affect each other.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use convert_case::{Case, Casing};
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn word_count_does_not_allocate() {
+    for case in [
+        Case::Camel,
+        Case::Snake,
+        Case::Kebab,
+        Case::Path,
+        Case::Dot,
+        Case::Flat,
+    ] {
+        let (count, allocated) = allocations(|| "getHTTPResponse_v2-final".word_count(case));
+        assert!(count > 0);
+        assert_eq!(0, allocated, "{:?}", case);
+    }
+}
+
+#[test]
+fn case_split_iter_does_not_allocate() {
+    let (count, allocated) = allocations(|| Case::Camel.split_iter("getTotalLength").count());
+    assert_eq!(3, count);
+    assert_eq!(0, allocated);
+}
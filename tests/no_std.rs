@@ -0,0 +1,51 @@
+//! Uses the library from a `no_std` crate with only `alloc`.  Run with
+//! `cargo test --no-default-features --test no_std` to check the API builds without `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+
+use convert_case::{field_names_in_case, split, Boundary, Case, Casing, Converter, Pattern};
+
+#[test]
+fn to_case() {
+    assert_eq!("my_var_name", "myVarName".to_case(Case::Snake));
+    assert_eq!(
+        "MyVarName",
+        String::from("my-var-name").to_case(Case::Pascal)
+    );
+    assert!("my-var-name".is_case(Case::Kebab));
+}
+
+#[test]
+fn converter() {
+    let conv = Converter::new()
+        .set_boundaries(&[Boundary::Space])
+        .set_pattern(Pattern::Uppercase)
+        .set_delim(".");
+    assert_eq!("MY.VAR.NAME", conv.convert("my var name"));
+}
+
+#[test]
+fn split_words() {
+    assert_eq!(
+        vec!["my", "Var", "Name"],
+        split("myVarName", &Boundary::defaults())
+    );
+}
+
+#[test]
+fn parse_case() {
+    assert_eq!(Ok(Case::UpperSnake), "upper snake".parse());
+}
+
+#[test]
+fn field_names() {
+    assert_eq!(
+        vec!["first_name", "last_name"],
+        field_names_in_case!(Case::Snake; firstName, lastName)
+    );
+}
@@ -21,6 +21,8 @@ fn str_type() {
 }
 
 #[test]
+// the borrow is the point of the test: it checks `Casing` on `&String`
+#[allow(clippy::needless_borrow)]
 fn string_ref_type() {
     let s: String = String::from("rust_programming_language");
     assert_eq!(